@@ -47,7 +47,7 @@ fn generate_cmd_table(file: &mut BufWriter<File>, path_str: &str, table_name: &s
     for cmd in &commands {
         // e.g., for "say.rs", creates `SayCommand`
         let struct_name = cmd.to_upper_camel_case();
-        let module_name = if cmd == "return" {"r#return"} else {cmd};
+        let module_name = if cmd == "return" || cmd == "type" {format!("r#{cmd}")} else {cmd.to_string()};
         let full_module_path = if path_str == "src/cmd" {
             format!("{}", module_name)
         } else {
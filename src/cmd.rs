@@ -11,13 +11,15 @@ pub(crate) mod say;
 pub(crate) mod ask;
 mod set;
 mod look;
-mod dig;
+pub(crate) mod dig;
 mod dmg;
 pub(crate) mod translocate;
 pub(crate) use translocate::translocate;
 mod goto;
 pub(crate) mod help;
 mod r#return;
+pub(crate) mod aedit;
+pub(crate) mod iedit;
 pub(crate) mod hedit;
 pub(crate) mod redit;
 mod abort;
@@ -43,6 +45,27 @@ mod shutdown;
 mod open;
 mod close;
 mod lock;
+mod lostfound;
+mod snapshot;
+mod bundle;
+mod copyover;
+mod mob;
+mod path;
+mod walkto;
+mod map;
+mod search;
+mod secrets;
+mod time;
+mod wcheck;
+mod builders;
+mod undo;
+mod redo;
+mod diff;
+mod rdelete;
+mod rclone;
+mod rtemplate;
+mod drafts;
+mod publish;
 
 /// Player locker.
 type PlayerLock = Arc<RwLock<Player>>;
@@ -94,12 +117,15 @@ pub async fn parse_and_execute<'a>(mut ctx: CommandCtx<'_>) -> ClientState {
             ctx.args.split_once(' ').unwrap_or((ctx.args.trim(), ""))
         };
     ctx.args = args;
+    let raw_command = command;
     
     let table = match state {
         ClientState::Playing => &COMMANDS,
         ClientState::Editing { ref mode, .. } => match mode {
             EditorMode::Room { .. } => &REDIT_COMMANDS,
             EditorMode::Help { .. } => &HEDIT_COMMANDS,
+            EditorMode::Area { .. } => &AEDIT_COMMANDS,
+            EditorMode::Item { .. } => &IEDIT_COMMANDS,
         },
         _ => {// Should not happen, but …
             log::error!("Player state '{:?}' invalid for commands processing!?", state);
@@ -124,6 +150,9 @@ pub async fn parse_and_execute<'a>(mut ctx: CommandCtx<'_>) -> ClientState {
         }
     } else if let Some(dir) = command.as_cardinal() {
         GotoCommand.exec({ctx.args = dir.as_str(); &mut ctx}).await;
+    } else if let Ok(Direction::Custom(_)) = Direction::try_from_known(&command) {
+        // registered custom directions work as movement shortcuts too.
+        GotoCommand.exec({ctx.args = raw_command; &mut ctx}).await;
     } else {
         tell_user_unk!(ctx.writer);
     }
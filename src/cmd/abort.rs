@@ -17,6 +17,8 @@ impl Command for AbortCommand {
                 match mode {
                     EditorMode::Help => { p.hedit = None; },
                     EditorMode::Room => { p.redit = None; },
+                    EditorMode::Area => { p.aedit = None; },
+                    EditorMode::Item => { p.iedit = None; },
                 }
             }
             _ => ()
@@ -0,0 +1,148 @@
+//! Area Editor - AEdit.
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use crate::{cmd::{Command, CommandCtx}, string::slug::as_id, tell_user, traits::IdentityQuery, util::{clientstate::EditorMode, history::EditHistory, Editor}, validate_area_owner, validate_builder, world::area::Area, ClientState};
+
+pub mod contributor;
+pub mod desc;
+pub mod owner;
+pub mod reset;
+pub mod save;
+pub mod tick;
+pub mod title;
+
+pub struct AeditCommand;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AeditState {
+    pub entry: Area,
+    pub dirty: bool,
+    #[serde(skip, default)]
+    pub history: EditHistory<Area>,
+}
+
+impl AeditState {
+    /// Remember the entry as-is, about to be changed.
+    pub fn checkpoint(&mut self) {
+        self.history.record(&self.entry);
+        self.dirty = true;
+    }
+}
+
+#[async_trait]
+impl Command for AeditCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() && ctx.player.read().await.aedit.is_none() {
+            return tell_user!(ctx.writer,
+                "AREA-ID missing and no previous AEdit session stored.\n\
+                Which area you want to edit? In case of the current one, use '<c yellow>aedit this</c>'\n");
+        }
+
+        {
+            let mut g = ctx.player.write().await;
+            if let Some(ed) = g.aedit.as_ref() {
+                if !ctx.args.is_empty() && ed.entry.id() != ctx.args {
+                    if ed.dirty {
+                        return tell_user!(ctx.writer, "<c red>Warning!</c> Unsaved edits - '<c yellow>save</c>' or '<c yellow>abort</c>' first.\n");
+                    }
+                } else {
+                    let id = ed.entry.id.clone();
+                    g.push_state(ClientState::Editing { mode: EditorMode::Area });
+                    return tell_user!(ctx.writer, "Resuming AEdit('{}') session.\n", id);
+                }
+            }
+        }
+
+        let id = match ctx.args {
+            "this"|"here" => {
+                let location = ctx.player.read().await.location.clone();
+                let room = ctx.world.read().await.rooms.get(&location).cloned();
+                match room {
+                    Some(r) => r.read().await.parent_id.clone(),
+                    None => return tell_user!(ctx.writer, "You're nowhere in particular…\n")
+                }
+            },
+            other => match as_id(other) {
+                Ok(id) => id,
+                Err(_) => return tell_user!(ctx.writer, "<c red>Error:</c> '{}' won't do as an area ID.\n", other)
+            }
+        };
+        validate_area_owner!(ctx, id);
+
+        let existing = ctx.world.read().await.areas.get(&id).cloned();
+        let mut g = ctx.player.write().await;
+        if let Some(existing_entry) = existing {
+            log::info!("Player '{}' editing existing area '{}'", g.id(), id);
+
+            g.aedit = Some(AeditState {
+                entry: existing_entry.read().await.clone(),
+                dirty: false,
+                history: EditHistory::default()
+            });
+        } else {
+            log::info!("Player '{}' editing new area '{}'", g.id(), id);
+
+            g.aedit = Some(AeditState {
+                entry: Area::new(&id),
+                dirty: true,
+                history: EditHistory::default()
+            });
+        }
+
+        g.push_state(ClientState::Editing { mode: EditorMode::Area });
+    }
+}
+
+/// Apply `+name`/`-name` (bare name = add) changes in `args` to `names`.
+pub(crate) fn change_names(names: &HashSet<String>, args: &str) -> HashSet<String> {
+    let mut names = names.clone();
+    for part in args.split_whitespace() {
+        match (part.strip_prefix('+'), part.strip_prefix('-')) {
+            (_, Some(name)) => { names.remove(&name.to_lowercase()); },
+            (Some(name), _) => { names.insert(name.to_lowercase()); },
+            _ => { names.insert(part.to_lowercase()); }
+        }
+    }
+    names
+}
+
+/// List `names`, sorted, or `-` if none.
+pub(crate) fn fmt_names(names: &HashSet<String>) -> String {
+    if names.is_empty() {
+        return "-".into();
+    }
+    let mut names = names.iter().map(|n| n.as_str()).collect::<Vec<_>>();
+    names.sort();
+    names.join(", ")
+}
+
+impl Editor for AeditState {
+    fn set_description(&mut self, desc: &str) {
+        self.checkpoint();
+        self.entry.description = desc.into();
+    }
+}
+
+impl Editor for Option<AeditState> {
+    fn set_description(&mut self, desc: &str) {
+        if let Some(state) = self {
+            state.set_description(desc);
+        }
+    }
+}
+
+#[cfg(test)]
+mod aedit_tests {
+    use super::*;
+
+    #[test]
+    fn change_names_multibyte() {
+        let names = HashSet::from(["bob".to_string()]);
+        let names = change_names(&names, "+Ärrä é -bob");
+        assert_eq!("ärrä, é", fmt_names(&names));
+    }
+}
@@ -0,0 +1,30 @@
+//! [Area][crate::world::area::Area] contributors.
+use async_trait::async_trait;
+use crate::{access_ed_entry, cmd::{Command, CommandCtx, aedit::{change_names, fmt_names}}, show_help_if_needed, tell_user, validate_builder};
+
+pub struct ContributorCommand;
+
+#[async_trait]
+impl Command for ContributorCommand {
+    /// AEdit 'contributor [+name|-name …]'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            return tell_user!(ctx.writer, "Contributors: <c cyan>{}</c>\n", fmt_names(&access_ed_entry!(ctx, aedit).contributors));
+        }
+        show_help_if_needed!(ctx, "aedit-contributor");
+
+        {
+            let mut g = ctx.player.write().await;
+            let ed = g.aedit.as_mut().unwrap();
+            let req_change = change_names(&ed.entry.contributors, ctx.args);
+            if req_change != ed.entry.contributors {
+                ed.checkpoint();
+                ed.entry.contributors = req_change;
+            }
+        }
+
+        ContributorCommand.exec({ctx.args = ""; ctx}).await;
+    }
+}
@@ -0,0 +1,34 @@
+//! [Area][crate::world::area::Area] owners.
+use async_trait::async_trait;
+use crate::{access_ed_entry, cmd::{Command, CommandCtx, aedit::{change_names, fmt_names}}, show_help_if_needed, tell_user, validate_builder};
+
+pub struct OwnerCommand;
+
+#[async_trait]
+impl Command for OwnerCommand {
+    /// AEdit 'owner [+name|-name …]'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            return tell_user!(ctx.writer, "Owners: <c cyan>{}</c>\n", fmt_names(&access_ed_entry!(ctx, aedit).owners));
+        }
+        show_help_if_needed!(ctx, "aedit-owner");
+
+        if !ctx.player.read().await.access.is_admin() {
+            return tell_user!(ctx.writer, "Only admins may manage area owners.\n");
+        }
+
+        {
+            let mut g = ctx.player.write().await;
+            let ed = g.aedit.as_mut().unwrap();
+            let req_change = change_names(&ed.entry.owners, ctx.args);
+            if req_change != ed.entry.owners {
+                ed.checkpoint();
+                ed.entry.owners = req_change;
+            }
+        }
+
+        OwnerCommand.exec({ctx.args = ""; ctx}).await;
+    }
+}
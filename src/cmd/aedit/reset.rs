@@ -0,0 +1,53 @@
+//! [Area][crate::world::area::Area] reset settings.
+use async_trait::async_trait;
+use crate::{cmd::{Command, CommandCtx}, show_help, string::boolean::BooleanCheckExt, tell_user, util::AsSetting, validate_builder};
+
+pub struct ResetCommand;
+
+#[async_trait]
+impl Command for ResetCommand {
+    /// AEdit 'reset [period <seconds>|empty <on|off>]'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            let (period, when_empty, count) = {
+                let p = ctx.player.read().await;
+                let a = &p.aedit.as_ref().unwrap().entry;
+                (a.reset_period, a.reset_when_empty, a.resets.len())
+            };
+            let period = if period == 0 {"never (no timed resets)".into()} else {format!("every {} seconds", period)};
+            return tell_user!(ctx.writer,
+                "<c yellow>-[ RESETS ]-</c>\n  Period: <c cyan>{}</c>\n  When empty: <c cyan>{}</c>\n  Resets defined: <c cyan>{}</c>\n",
+                period, when_empty.as_state(), count);
+        }
+
+        let Some((what, value)) = ctx.args.split_once(' ') else { show_help!(ctx, "aedit-reset"); };
+        let value = value.trim();
+        {
+            let mut g = ctx.player.write().await;
+            let ed = g.aedit.as_mut().unwrap();
+            match what {
+                "period" => {
+                    let Ok(period) = value.parse::<u64>() else {
+                        drop(g);
+                        return tell_user!(ctx.writer, "<c red>Error:</c> Reset period is given in seconds, 0 for none.\n");
+                    };
+                    if ed.entry.reset_period != period {
+                        ed.checkpoint();
+                        ed.entry.reset_period = period;
+                    }
+                },
+                "empty" if value.is_boolean() => {
+                    if ed.entry.reset_when_empty != value.is_true() {
+                        ed.checkpoint();
+                        ed.entry.reset_when_empty = value.is_true();
+                    }
+                },
+                _ => { drop(g); show_help!(ctx, "aedit-reset"); }
+            }
+        }
+
+        ResetCommand.exec({ctx.args = ""; ctx}).await;
+    }
+}
@@ -0,0 +1,52 @@
+//! AEdit/'save' — save [Area][crate::world::area::Area] edits.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::{cmd::{Command, CommandCtx}, tell_user, traits::{IdentityQuery, save::DoesSave}, validate_area_owner, validate_builder, world::draft::Draft};
+
+pub struct SaveCommand;
+
+#[async_trait]
+impl Command for SaveCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+        let Some(mut entry) = ctx.player.read().await.aedit.as_ref().map(|ed| ed.entry.clone()) else {
+            log::debug!("Where'd the stored AEdit state go for '{}'?!", ctx.player.read().await.id());
+            return tell_user!(ctx.writer, "You could've sworn you were editing an area, but…\n");
+        };
+        validate_area_owner!(ctx, entry.id);
+
+        let existing = ctx.world.read().await.areas.get(entry.id()).cloned();
+        let res = match existing {
+            Some(area) => {
+                let mut lock = area.write().await;
+                lock.shallow_copy(&entry);
+                lock.save().await
+            },
+            None => {
+                // a brand new area - put it into play (as a draft) and get it listed in the world file.
+                entry.parent = Arc::downgrade(ctx.world);
+                entry.draft = Some(Draft::new(ctx.player.read().await.id()));
+                let id = entry.id().to_string();
+                let area = Arc::new(RwLock::new(entry));
+                let mut w = ctx.world.write().await;
+                w.areas.insert(id, area.clone());
+                match area.write().await.save().await {
+                    Ok(()) => w.save_world_file(),
+                    e => e
+                }
+            }
+        };
+        if let Err(e) = res {
+            log::error!("FATAL: save error '{e:?}'");
+            return tell_user!(ctx.writer, "Something went awry…\n");
+        }
+
+        if let Some(ed) = ctx.player.write().await.aedit.as_mut() {
+            ed.dirty = false;
+        }
+        tell_user!(ctx.writer, "Edits stored.\n");
+    }
+}
@@ -0,0 +1,35 @@
+//! How often the [Area][crate::world::area::Area] acts.
+use async_trait::async_trait;
+use crate::{access_ed_entry, cmd::{Command, CommandCtx}, show_help_if_needed, tell_user, validate_builder};
+
+pub struct TickCommand;
+
+#[async_trait]
+impl Command for TickCommand {
+    /// AEdit 'tick'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            return tell_user!(ctx.writer, "Area acts once every <c cyan>{}</c> tick(s).\n", access_ed_entry!(ctx, aedit).tick_modulo);
+        }
+        show_help_if_needed!(ctx, "aedit-tick");
+
+        let Ok(modulo) = ctx.args.trim().parse::<u8>() else {
+            return tell_user!(ctx.writer, "<c red>Error:</c> Tick modulo is a number between 1 and 255.\n");
+        };
+        if modulo == 0 {
+            return tell_user!(ctx.writer, "<c red>Error:</c> Tick modulo is a number between 1 and 255.\n");
+        }
+        {
+            let mut g = ctx.player.write().await;
+            let ed = g.aedit.as_mut().unwrap();
+            if ed.entry.tick_modulo != modulo {
+                ed.checkpoint();
+                ed.entry.tick_modulo = modulo;
+            }
+        }
+
+        TickCommand.exec({ctx.args = ""; ctx}).await;
+    }
+}
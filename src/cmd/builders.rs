@@ -0,0 +1,89 @@
+//! Area-scoped builder permissions.
+//!
+//! Owners may grant/revoke contributors in their own areas; only admins may
+//! manage owners.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, show_help, show_help_if_needed, tell_user, tell_user_unk, traits::{IdentityQuery, save::DoesSave}};
+
+pub struct BuildersCommand;
+
+#[async_trait]
+impl Command for BuildersCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        let (admin, builder, me) = {
+            let p = ctx.player.read().await;
+            (p.access.is_admin(), p.access.is_builder(), p.id().to_lowercase())
+        };
+        if !builder {
+            return tell_user_unk!(ctx.writer);
+        }
+        show_help_if_needed!(ctx, "builders");
+
+        let args = ctx.args.split_whitespace().collect::<Vec<&str>>();
+        let Some(area) = ctx.world.read().await.areas.get(args[0]).cloned() else {
+            tell_user!(ctx.writer, "No such area as '{}'.\n", args[0]);
+            return;
+        };
+        let mut a = area.write().await;
+        if !admin && !a.is_owner(&me) {
+            log::warn!("Builder '{}' denied managing builders of area '{}'.", me, a.id());
+            tell_user!(ctx.writer, "You're not authorized to manage builders of area '{}'.\n", a.id());
+            return;
+        }
+
+        let Some(sub) = args.get(1) else {
+            let mut out = format!("<c yellow>-[ BUILDERS: {} ]-</c>\n", a.id());
+            if a.owners.is_empty() && a.contributors.is_empty() {
+                out.push_str("  <c gray>Nobody listed - admins only.</c>\n");
+            }
+            let mut owners = a.owners.iter().collect::<Vec<_>>();
+            owners.sort();
+            let mut contributors = a.contributors.iter().collect::<Vec<_>>();
+            contributors.sort();
+            for who in owners { out.push_str(&format!("  <c cyan>{}</c> <c gray>(owner)</c>\n", who)); }
+            for who in contributors { out.push_str(&format!("  <c cyan>{}</c>\n", who)); }
+            tell_user!(ctx.writer, "{}", out);
+            return;
+        };
+        let Some(who) = args.get(2).map(|w| w.to_lowercase()) else { show_help!(ctx, "builders"); };
+        let as_owner = args.get(3).is_some_and(|o| o.eq_ignore_ascii_case("owner"));
+        if (as_owner || a.is_owner(&who)) && !admin {
+            tell_user!(ctx.writer, "Only admins may manage area owners.\n");
+            return;
+        }
+
+        match sub.to_lowercase().as_str() {
+            "grant" => {
+                let added = if as_owner {
+                    a.contributors.remove(&who);
+                    a.owners.insert(who.clone())
+                } else {
+                    a.contributors.insert(who.clone())
+                };
+                if !added {
+                    tell_user!(ctx.writer, "'{}' was already listed in area '{}'.\n", who, a.id());
+                    return;
+                }
+                log::info!("'{}' granted '{}' {} rights in area '{}'.", me, who, if as_owner {"owner"} else {"contributor"}, a.id());
+                tell_user!(ctx.writer, "'{}' may now build within area '{}'{}.\n", who, a.id(), if as_owner {" as an owner"} else {""});
+            },
+
+            "revoke" => {
+                if !(a.owners.remove(&who) | a.contributors.remove(&who)) {
+                    tell_user!(ctx.writer, "'{}' wasn't listed in area '{}' to begin with.\n", who, a.id());
+                    return;
+                }
+                log::info!("'{}' revoked '{}' build rights in area '{}'.", me, who, a.id());
+                tell_user!(ctx.writer, "'{}' may no longer build within area '{}'.\n", who, a.id());
+            },
+
+            _ => { show_help!(ctx, "builders"); }
+        }
+
+        if let Err(e) = a.save().await {
+            log::error!("Error saving area '{}': {:?}", a.id(), e);
+            tell_user!(ctx.writer, "<c red>Error saving the area!</c>\n");
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Area bundles — export an area for another server, or import one from it.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, show_help, show_help_if_needed, tell_user, validate_admin, world::bundle::AreaBundle};
+
+pub struct BundleCommand;
+
+#[async_trait]
+impl Command for BundleCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_admin!(ctx);
+        show_help_if_needed!(ctx, "bundle");
+
+        let args = ctx.args.split_whitespace().collect::<Vec<&str>>();
+        if args.len() < 2 {
+            show_help!(ctx, "bundle");
+        }
+
+        match args[0].to_lowercase().as_str() {
+            "export" => {
+                let bundle = match AreaBundle::export(&*ctx.world.read().await, args[1]).await {
+                    Ok(b) => b,
+                    Err(e) => return tell_user!(ctx.writer, "{}\n", e),
+                };
+                match bundle.write().await {
+                    Ok(path) => {
+                        log::info!("Area '{}' exported to '{}'.", args[1], path);
+                        tell_user!(ctx.writer, "Area '{}' with {} room{} exported to '{}'.\n",
+                            args[1], bundle.rooms.len(), if bundle.rooms.len() == 1 {""} else {"s"}, path);
+                    },
+                    Err(e) => {
+                        log::error!("Bundle export of '{}' failed: {e:?}", args[1]);
+                        tell_user!(ctx.writer, "Something went awry…\n");
+                    }
+                }
+            },
+
+            // import <bundle> [prefix <pfx>] [force]
+            "import" => {
+                let mut prefix = None;
+                let mut force = false;
+                let mut rest = args[2..].iter();
+                while let Some(a) = rest.next() {
+                    match a.to_lowercase().as_str() {
+                        "prefix" => prefix = rest.next(),
+                        "force" => force = true,
+                        _ => { show_help!(ctx, "bundle"); }
+                    }
+                }
+
+                let mut bundle = match AreaBundle::read(args[1]).await {
+                    Ok(b) => b,
+                    Err(e) => return tell_user!(ctx.writer, "Cannot read bundle '{}': {}\n", args[1], e),
+                };
+                if let Some(prefix) = prefix {
+                    bundle.remap(prefix);
+                }
+
+                let mut w = ctx.world.write().await;
+                let issues = bundle.check(&w);
+                if !issues.is_empty() {
+                    let fatal = issues.iter().any(|i| i.is_fatal());
+                    let mut out = format!("<c {}>Issues with '{}':</c>\n", if fatal {"red"} else {"yellow"}, args[1]);
+                    for i in &issues {
+                        out.push_str(&format!("  - {}\n", i));
+                    }
+                    tell_user!(ctx.writer, "{}", out);
+                    if fatal {
+                        tell_user!(ctx.writer, "Import aborted. Try with '<c yellow>prefix</c> <c cyan>PFX</c>' to sidestep collisions.\n");
+                        return;
+                    }
+                    if !force {
+                        tell_user!(ctx.writer, "Import aborted. Use '<c yellow>force</c>' to import regardless.\n");
+                        return;
+                    }
+                }
+
+                let (area_id, num_rooms) = (bundle.area.id.clone(), bundle.rooms.len());
+                let installed = match bundle.install(&mut w).await {
+                    Ok(i) => i,
+                    Err(e) => {
+                        log::error!("Bundle import of '{}' failed: {e:?}", args[1]);
+                        tell_user!(ctx.writer, "Something went awry…\n");
+                        return;
+                    }
+                };
+                log::info!("Area '{}' imported from bundle '{}'.", area_id, args[1]);
+                tell_user!(ctx.writer, "Area '{}' with {} room{} and {} blueprint{} imported.\n",
+                    area_id, num_rooms, if num_rooms == 1 {""} else {"s"},
+                    installed.blueprints.len(), if installed.blueprints.len() == 1 {""} else {"s"});
+                if !installed.skipped_blueprints.is_empty() {
+                    tell_user!(ctx.writer, "<c yellow>Blueprints skipped</c> (already known, or bad ID): {}\n", installed.skipped_blueprints.join(", "));
+                }
+            },
+
+            _ => { show_help!(ctx, "bundle"); }
+        }
+    }
+}
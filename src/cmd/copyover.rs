@@ -0,0 +1,26 @@
+//! Copyover — warm reboot without dropping anyone's connection.
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, tell_user, util::{Broadcast, comm::SystemBroadcastType, copyover::copyover}, validate_admin};
+
+pub struct CopyoverCommand;
+
+#[async_trait]
+impl Command for CopyoverCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_admin!(ctx);
+
+        log::info!("Copyover sequence initiated: Players notified…");
+        let _ = ctx.tx.send(Broadcast::System(SystemBroadcastType::Copyover {
+            message: "<c yellow>*** The world shimmers and shifts around you… hold on. ***</c>".into()
+        }));
+        // Give the notices a moment to get through.
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        let e = copyover(ctx.world).await;
+        log::error!("Copyover failed: {e:?}");
+        tell_user!(ctx.writer, "<c red>Copyover failed</c>: {}\n", e);
+    }
+}
@@ -0,0 +1,60 @@
+//! 'diff' - what has changed within some editor, compared to the live entry.
+use async_trait::async_trait;
+use crate::{cmd::{Command, CommandCtx}, item::blueprint::{BLUEPRINTS, Blueprint}, tell_user, tell_user_unk, util::{Help, clientstate::EditorMode, history::{field_diff, fmt_diff}}, world::{area::Area, room::Room}, ClientState};
+
+pub struct DiffCommand;
+
+#[async_trait]
+impl Command for DiffCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        let ClientState::Editing { mode } = ctx.player.read().await.state() else {
+            // NOTE: 'diff' is a no-op when user is not within some editor context.
+            return tell_user_unk!(ctx.writer);
+        };
+
+        let changes = match mode {
+            EditorMode::Help => {
+                let (entry, original) = {
+                    let p = ctx.player.read().await;
+                    let ed = p.hedit.as_ref().unwrap();
+                    (ed.entry.clone(), ed.original.clone())
+                };
+                let live = match original {
+                    Some(h) => h.read().await.clone(),
+                    None => Help::new(&entry.id)
+                };
+                field_diff(&live, &entry, &[])
+            },
+            EditorMode::Room => {
+                let entry = ctx.player.read().await.redit.as_ref().unwrap().entry.clone();
+                let live = ctx.world.read().await.rooms.get(&entry.id).cloned();
+                let live = match live {
+                    Some(r) => r.read().await.clone(),
+                    None => {
+                        let mut r = Room::blank(Some(&entry.id));
+                        r.parent_id = entry.parent_id.clone();
+                        r
+                    }
+                };
+                // contents aren't REdit's business.
+                field_diff(&live, &entry, &["contents"])
+            },
+            EditorMode::Area => {
+                let entry = ctx.player.read().await.aedit.as_ref().unwrap().entry.clone();
+                let live = ctx.world.read().await.areas.get(&entry.id).cloned();
+                let live = match live {
+                    Some(a) => a.read().await.clone(),
+                    None => Area::new(&entry.id)
+                };
+                field_diff(&live, &entry, &[])
+            },
+            EditorMode::Item => {
+                let entry = ctx.player.read().await.iedit.as_ref().unwrap().entry.clone();
+                let live = BLUEPRINTS.read().await.get(&entry.id).cloned()
+                    .unwrap_or_else(|| Blueprint::new(&entry.id));
+                field_diff(&live, &entry, &[])
+            },
+        };
+        tell_user!(ctx.writer, "{}", fmt_diff(&changes, "No changes."));
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use tokio::sync::RwLock;
-use crate::{cmd::{Command, CommandCtx, redit::ReditCommand, translocate::translocate}, show_help, tell_user, traits::IdentityQuery, util::direction::Direction, validate_builder, world::{exit::{Exit, state::ExitState}, room::Room}};
+use crate::{cmd::{Command, CommandCtx, redit::ReditCommand, translocate::translocate}, show_help, tell_user, traits::{IdentityQuery, save::DoesSave}, util::direction::Direction, validate_area_builder, validate_builder, world::{draft::Draft, exit::{Exit, state::ExitState}, room::{Room, template::{self, MAX_GRID_SIDE, RoomTemplate, grid_id}}}};
 
 pub struct DigCommand;
 
@@ -10,11 +10,23 @@ pub struct DigCommand;
 impl Command for DigCommand {
     async fn exec(&self, ctx: &mut CommandCtx<'_>) {
         validate_builder!(ctx);
+        let area_id = {
+            let location = ctx.player.read().await.location.clone();
+            match ctx.world.read().await.rooms.get(&location) {
+                Some(r) => r.read().await.parent_id.clone(),
+                None => String::new()
+            }
+        };
+        validate_area_builder!(ctx, area_id);
+
+        if let Some(args) = ctx.args.strip_prefix("grid ") {
+            return dig_grid(ctx, args).await;
+        }
 
         let vr = validate_args(ctx).await;
         if let Some((dir, id)) = vr {
             let id: String = id.into();
-            if create_and_link_room(ctx, dir, &id).await {
+            if create_and_link_room(ctx, dir, Room::blank(Some(&id)), None).await {
                 let source = ctx.player.read().await.location.clone();
                 let _ = translocate(ctx.world, Some(source), id.into(), ctx.player.clone()).await;
                 let cmd = ReditCommand;
@@ -25,13 +37,13 @@ impl Command for DigCommand {
 }
 
 /// Validate 'dig' arguments.
-async fn validate_args<'a>(ctx: &mut CommandCtx<'a>) -> Option<(Direction, &'a str)> {
+pub(crate) async fn validate_args<'a>(ctx: &mut CommandCtx<'a>) -> Option<(Direction, &'a str)> {
     let args: Vec<&str> = ctx.args.splitn(2, ' ').collect();
     if args.len() < 2 || args[0].starts_with('?') {
         show_help!(ctx, "dig"; None);
     }
 
-    let dir = match Direction::try_from_std(args[0]) {
+    let dir = match Direction::try_from_known(args[0]) {
         Ok(dir) => dir,
         Err(_) => {
             tell_user!(ctx.writer, "<c red>Error!</c> '{}' is not a valid direction.\n\n", args[0]);
@@ -66,22 +78,127 @@ async fn validate_args<'a>(ctx: &mut CommandCtx<'a>) -> Option<(Direction, &'a s
     Some((dir, new_room_id))
 }
 
+/// Dig a whole block of rooms off of a template, in one go.
+///
+/// # Args
+/// - `args`: `<dir> <cols>x<rows> <room-or-template> <id-prefix>`.
+async fn dig_grid(ctx: &mut CommandCtx<'_>, args: &str) {
+    let args = args.split_whitespace().collect::<Vec<&str>>();
+    if args.len() != 4 {
+        show_help!(ctx, "dig");
+    }
+    let Ok(dir) = Direction::try_from_known(args[0]) else {
+        return tell_user!(ctx.writer, "<c red>Error!</c> '{}' is not a valid direction.\n", args[0]);
+    };
+    let size = args[1].split_once('x').and_then(|(c, r)| Some((c.parse::<usize>().ok()?, r.parse::<usize>().ok()?)));
+    let Some((cols, rows)) = size.filter(|(c, r)| (1..=MAX_GRID_SIDE).contains(c) && (1..=MAX_GRID_SIDE).contains(r)) else {
+        return tell_user!(ctx.writer, "<c red>Error!</c> Grid size goes as COLSxROWS, each between 1 and {}.\n", MAX_GRID_SIDE);
+    };
+    let prefix = args[3];
+    if !prefix.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return tell_user!(ctx.writer, "<c red>Error!</c> Room IDs can only contain <c cyan>letters</c> (a-z), <c cyan>numbers</c> (0-9), <c cyan>hyphens</c> (-), and <c cyan>underscores</c> (_).\n");
+    }
+
+    let (mut rooms, template) = {
+        let w = ctx.world.read().await;
+        let Some(template) = template::resolve(&w, args[2]).await else {
+            return tell_user!(ctx.writer, "No room or template called '{}'.\n", args[2]);
+        };
+        let rooms = template::grid(&template, prefix, cols, rows);
+        if let Some(taken) = rooms.iter().find(|r| w.rooms.contains_key(&r.id)) {
+            return tell_user!(ctx.writer, "<c red>A room with ID '{}' already exists!</c>\n", taken.id);
+        }
+        let location = ctx.player.read().await.location.clone();
+        if let Some(curr_room) = w.rooms.get(&location) {
+            if curr_room.read().await.exits.contains_key(&dir) {
+                return tell_user!(ctx.writer, "That direction is already taken…\n");
+            }
+        }
+        (rooms, template)
+    };
+
+    // Enter the block from whichever corner keeps the way back clear of the grid's own exits.
+    let anchor = match dir {
+        Direction::North => grid_id(prefix, rows - 1, 0),
+        Direction::West => grid_id(prefix, 0, cols - 1),
+        _ => grid_id(prefix, 0, 0)
+    };
+    let Some(at) = rooms.iter().position(|r| r.id == anchor) else { return };
+    let anchor = rooms.swap_remove(at);
+    let anchor_id = anchor.id.clone();
+    if !create_and_link_room(ctx, dir, anchor, Some(&template)).await {
+        return;
+    }
+
+    // the rest of the block goes where the anchor went.
+    let mut w = ctx.world.write().await;
+    let (parent_id, parent) = match w.rooms.get(&anchor_id) {
+        Some(r) => { let r = r.read().await; (r.parent_id.clone(), r.parent.clone()) },
+        None => return
+    };
+    let count = rooms.len() + 1;
+    let author = ctx.player.read().await.id().to_string();
+    let mut to_save = vec![ctx.player.read().await.location.clone(), anchor_id];
+    for mut room in rooms {
+        room.parent_id = parent_id.clone();
+        room.parent = parent.clone();
+        room.draft = Some(Draft::new(&author));
+        let id = room.id.clone();
+        let room = Arc::new(RwLock::new(room));
+        register_in_area(&room).await;
+        w.rooms.insert(id.clone(), room);
+        to_save.push(id);
+    }
+    // the whole block (and the way there) has to survive a reboot.
+    for id in to_save {
+        let Some(room) = w.rooms.get(&id) else { continue };
+        if let Err(e) = room.write().await.save().await {
+            log::error!("Dig grid: could not save room '{}': {e:?}", id);
+            tell_user!(ctx.writer, "<c red>Error!</c> Room '{}' could not be saved…\n", id);
+        }
+    }
+    // …and the world file, which lists the rooms there are.
+    if let Err(e) = w.save_world_file() {
+        log::error!("Dig grid: could not save the world file: {e:?}");
+    }
+    drop(w);
+    tell_user!(ctx.writer, "{} rooms laid out, '<c cyan>{}</c>' … '<c cyan>{}</c>'.\n", count, grid_id(prefix, 0, 0), grid_id(prefix, rows - 1, cols - 1));
+}
+
+/// Let the [Room]'s area know of it — for ticks, resets and such.
+async fn register_in_area(room: &Arc<RwLock<Room>>) {
+    let (id, area) = {
+        let r = room.read().await;
+        (r.id.clone(), r.parent.upgrade())
+    };
+    if let Some(area) = area {
+        area.write().await.rooms.insert(id, Arc::downgrade(room));
+    }
+}
+
 /// Create a new [Room] at `dir`, making a bi-directional way there from current [Room].
 /// 
 /// # Args
 /// - `ctx`…
 /// - `dir` which way to make the new [Room].
-/// - `id` for the new [Room].
-async fn create_and_link_room(ctx: &mut CommandCtx<'_>, dir: Direction, id: &str) -> bool {
-    let curr_id = {
+/// - `room` to put in place; its ID must be unique.
+/// - `layout` to take the way back from, if any.
+pub(crate) async fn create_and_link_room(ctx: &mut CommandCtx<'_>, dir: Direction, mut room: Room, layout: Option<&RoomTemplate>) -> bool {
+    let (curr_id, author) = {
         let p = ctx.player.read().await;
-        p.location.clone()
+        (p.location.clone(), p.id().to_string())
     };
-    let mut room = Room::blank(Some(id));
+    let id = room.id.clone();
+    // fresh rooms stay out of players' reach until published.
+    room.draft = Some(Draft::new(&author));
     // by default we use None as key_id - install a lock later…
     if let Ok(opp) = dir.opposite() {
-        room.exits.insert(opp, Exit { destination: curr_id.clone(), state: ExitState::Open {key_id: None} });
-        tell_user!(ctx.writer, "FYI, don't forget to install a lock, if/when needed (or plausible to even have).\n");
+        let exit = match layout {
+            Some(t) => t.exit(&opp, &curr_id),
+            None => Exit { destination: curr_id.clone(), state: ExitState::Open {key_id: None}, hidden: None }
+        };
+        room.exits.insert(opp, exit);
+        tell_user!(ctx.writer, "FYI, use '<c yellow>door</c>', '<c yellow>key</c>' and '<c yellow>jam</c>' in REdit to install a door or a lock, if/when needed.\n");
     } else {
         tell_user!(ctx.writer, "Source exit '{:?}' doesn't have a clear opposite.\n = you have to craft return direction manually!\n", dir);
     }
@@ -93,16 +210,17 @@ async fn create_and_link_room(ctx: &mut CommandCtx<'_>, dir: Direction, id: &str
         room.parent_id = r.parent_id.clone();
         room.parent = r.parent.clone();
         lock = Arc::new(RwLock::new(room));
-        r.exits.insert(dir, Exit { destination: id.into(), state: ExitState::Open {key_id: None} });
+        r.exits.insert(dir, Exit { destination: id.clone(), state: ExitState::Open {key_id: None}, hidden: None });
         // we'll insert the room into World a bit later below…
     } else {
         log::error!("Player '{}' was in a non-existent room '{}'", ctx.player.read().await.id(), curr_id);
         return false;
     }
     // …pesky borrows made me put this line here instead of the if-block above…
-    w.rooms.insert(id.into(), lock.clone());
+    w.rooms.insert(id.clone(), lock.clone());
+    register_in_area(&lock).await;
     log::debug!("Room inserted.");
 
-    tell_user!(ctx.writer, format!("Blank room '<c cyan>{}</c>' created.\n", id));
+    tell_user!(ctx.writer, format!("Room '<c cyan>{}</c>' created (as a draft).\n", id));
     true
 }
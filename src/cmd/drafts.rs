@@ -0,0 +1,28 @@
+//! List drafts waiting to be published.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, tell_user, util::timestamp::{fmt_span, unix_now}, validate_builder, world::draft::pending};
+
+pub struct DraftsCommand;
+
+#[async_trait]
+impl Command for DraftsCommand {
+    /// 'drafts [author|area]'
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        let filter = ctx.args.trim().to_lowercase();
+        let list = pending(&*ctx.world.read().await).await;
+        let now = unix_now();
+        let lines = list.iter()
+            .filter(|d| filter.is_empty() || d.draft.author.eq_ignore_ascii_case(&filter) || d.area_id == filter)
+            .map(|d| format!("  {:<8} <c yellow>{:<20}</c> {:<16} <c cyan>{:<12}</c> {} ago\n",
+                d.kind.to_string(), d.id, d.area_id, d.draft.author, fmt_span(now.saturating_sub(d.draft.since))))
+            .collect::<String>();
+        if lines.is_empty() {
+            return tell_user!(ctx.writer, "No drafts pending.\n");
+        }
+        tell_user!(ctx.writer, "<c yellow>-[ DRAFTS ]-</c>\n  {:<8} {:<20} {:<16} {:<12} {}\n{}",
+            "KIND", "ID", "AREA", "AUTHOR", "SAVED", lines);
+    }
+}
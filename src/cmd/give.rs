@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use lazy_static::lazy_static;
 use regex::Regex;
 
-use crate::{cmd::{Command, CommandCtx}, do_in_current_room, force_item_to_player, item::{Item, inventory::Storage}, show_help, show_help_if_needed, tell_user, traits::{IdentityQuery, Owned}, util::Broadcast};
+use crate::{cmd::{Command, CommandCtx}, do_in_current_room, force_item_to_player, item::{Item, inventory::Storage}, player::journal::JournalEntry, show_help, show_help_if_needed, tell_user, traits::{IdentityQuery, Owned}, util::Broadcast};
 
 pub struct GiveCommand;
 
@@ -46,7 +46,11 @@ impl Command for GiveCommand {
 
             let (giver, item) = {
                 let mut p = ctx.player.write().await;
-                (p.id().to_string(), p.inventory.take_out(what))
+                let item = p.inventory.take_out(what);
+                if let Ok(item) = &item {
+                    p.journal(JournalEntry::Lost(item.id().into()));
+                }
+                (p.id().to_string(), item)
             };
 
             match item {
@@ -57,12 +61,14 @@ impl Command for GiveCommand {
                         let mut recv = target_arc.write().await;
                         let _ = item.set_owner(target_id);
                         let item_name = item.id().to_string();
+                        let gained = JournalEntry::Gained(item.clone());
                         if let Err(e) = recv.inventory.try_insert(item) {
                             // fail: do return to sender!
                             item = Item::from(e);
                             let _ = item.set_owner(&giver);
                             force_item_to_player!(ctx, item);
                         } else {
+                            recv.journal(gained);
                             tell_user!(ctx.writer, "You hand out '{}' to '{}'.\n", what, to);
                             let _ = ctx.tx.send(Broadcast::Tell {
                                 subtype: None,
@@ -20,7 +20,16 @@ impl Command for GotoCommand {
         // See if room has corresponding exit...
         let mut do_translocate_to = None;
         do_in_current_room!(ctx, |room|{
-            if let Some(exit) = room.read().await.exits.get(&exit).cloned() {
+            let blocked = if ctx.player.read().await.access.is_admin() { None } else {
+                room.read().await.mobs.values().find_map(|m| m.blocks(&exit))
+            };
+            if let Some(msg) = blocked {
+                tell_user!(ctx.writer, "{}\n", msg);
+            } else if let Some(exit) = {
+                // hidden ways are as good as walls until found.
+                let (r, p) = (room.read().await, ctx.player.read().await);
+                r.exits.get(&exit).filter(|e| e.visible_to(&r.id, &exit, &p)).cloned()
+            } {
                 if exit.is_closed() {
                     tell_user!(ctx.writer, "Well… the way to {} is {}. Try open it, maybe?\n", exit.destination, exit.state);
                 } else if ctx.world.read().await.rooms.get(&exit.destination).is_some() {
@@ -36,6 +45,10 @@ impl Command for GotoCommand {
         });
 
         if let Some((source, destination)) = do_translocate_to {
+            // drafts are off limits for all but the builders.
+            if !ctx.player.read().await.access.is_builder() && ctx.world.read().await.is_draft(&destination).await {
+                return tell_user!(ctx.writer, "You have no idea how to go there … Find another route?\n");
+            }
             let _ = translocate(&ctx.world, Some(source), destination, ctx.player.clone()).await;
             cmd_exec!(ctx, look);
         }
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
-use crate::{ClientState, help_reg_lock, cmd::{Command, CommandCtx}, show_help_if_needed, tell_user, util::{Editor, Help, clientstate::EditorMode}, validate_builder};
+use crate::{ClientState, help_reg_lock, cmd::{Command, CommandCtx}, show_help_if_needed, tell_user, util::{Editor, Help, clientstate::EditorMode, history::EditHistory}, validate_area_builder, validate_builder};
 
 pub(crate) mod desc;
 pub(crate) mod data;
@@ -12,6 +12,8 @@ pub(crate) mod title;
 pub(crate) mod alias;
 pub(crate) mod admin;
 pub(crate) mod builder;
+pub(crate) mod knowledge;
+pub(crate) mod area;
 
 pub struct HeditCommand;
 
@@ -21,6 +23,16 @@ pub struct HeditState {
     #[serde(skip, default)]
     pub original: Option<Arc<RwLock<Help>>>,
     pub dirty: bool,
+    #[serde(skip, default)]
+    pub history: EditHistory<Help>,
+}
+
+impl HeditState {
+    /// Remember the entry as-is, about to be changed.
+    pub fn checkpoint(&mut self) {
+        self.history.record(&self.entry);
+        self.dirty = true;
+    }
 }
 
 #[async_trait]
@@ -32,6 +44,15 @@ impl Command for HeditCommand {
         }
         show_help_if_needed!(ctx, "hedit-internal-commands");
 
+        // area-linked topics need build rights within the area.
+        let existing = help_reg_lock!(read).0.get(ctx.args).cloned();
+        if let Some(existing) = &existing {
+            let area_id = existing.read().await.area.clone();
+            if let Some(area_id) = area_id {
+                validate_area_builder!(ctx, area_id);
+            }
+        }
+
         let mut pg = ctx.player.write().await;
         if pg.hedit.is_some() {
             let ed = pg.hedit.as_mut().unwrap();
@@ -46,18 +67,20 @@ impl Command for HeditCommand {
             }
         }
 
-        if let Some(existing_entry) = help_reg_lock!(read).0.get(ctx.args) {
+        if let Some(existing_entry) = existing {
             // Make a working copy of an existing entry.
             pg.hedit = Some(HeditState {
                 entry: existing_entry.read().await.clone(),
                 original: Some(existing_entry.clone()),
-                dirty: false
+                dirty: false,
+                history: EditHistory::default()
             });
         } else {
             pg.hedit = Some(HeditState {
                 entry: Help::new(ctx.args),
                 original: None,
-                dirty: true
+                dirty: true,
+                history: EditHistory::default()
             });
         }
 
@@ -67,7 +90,7 @@ impl Command for HeditCommand {
 
 impl Editor for HeditState {
     fn set_description(&mut self, desc: &str) {
-        self.dirty = true;
+        self.checkpoint();
         self.entry.description = desc.into();
     }
 }
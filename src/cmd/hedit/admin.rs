@@ -23,7 +23,7 @@ impl Command for AdminCommand {
 
         let mut g = ctx.player.write().await;
         let ed = g.hedit.as_mut().unwrap();
-        ed.dirty = true;
+        ed.checkpoint();
         ed.entry.admin = ctx.args.is_true();
         tell_user!(ctx.writer, "Admin flag is now {}.\n", ed.entry.admin.as_state());
     }
@@ -52,7 +52,7 @@ impl Command for AliasCommand {
         let net_add: Vec<_> = req_change.difference(&orig_aliases).cloned().collect();
         let net_rem: Vec<_> = orig_aliases.difference(&req_change).cloned().collect();
         if !net_add.is_empty() || !net_rem.is_empty() {
-            ed.dirty = true;
+            ed.checkpoint();
             ed.entry.aliases = req_change;
         }
 
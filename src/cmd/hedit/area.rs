@@ -0,0 +1,44 @@
+//! <HEdit> 'area' subcommand.
+use async_trait::async_trait;
+use crate::{cmd::{help::HelpCommand, Command, CommandCtx}, tell_user, validate_area_builder, validate_builder};
+
+pub struct AreaCommand;
+
+#[async_trait]
+impl Command for AreaCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            return tell_user!(ctx.writer, "Linked area: {}\n",
+                ctx.player.read().await.hedit.as_ref().unwrap().entry.area.as_deref().unwrap_or("-"));
+        }
+
+        if ctx.args.starts_with('?') {
+            let cmd = HelpCommand;
+            return cmd.exec({ctx.args = "hedit-area"; ctx}).await;
+        }
+
+        let area = match ctx.args.trim() {
+            "none"|"-" => None,
+            id => {
+                if !ctx.world.read().await.areas.contains_key(id) {
+                    return tell_user!(ctx.writer, "No such area as '{}'.\n", id);
+                }
+                Some(id.to_string())
+            }
+        };
+        if let Some(area_id) = &area {
+            validate_area_builder!(ctx, area_id);
+        }
+
+        let mut g = ctx.player.write().await;
+        let ed = g.hedit.as_mut().unwrap();
+        ed.checkpoint();
+        ed.entry.area = area;
+        match &ed.entry.area {
+            Some(id) => tell_user!(ctx.writer, "Entry now belongs to area '{}'.\n", id),
+            None => tell_user!(ctx.writer, "Entry no longer belongs to any area.\n"),
+        }
+    }
+}
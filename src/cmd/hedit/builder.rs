@@ -23,7 +23,7 @@ impl Command for BuilderCommand {
 
         let mut g = ctx.player.write().await;
         let ed = g.hedit.as_mut().unwrap();
-        ed.dirty = true;
+        ed.checkpoint();
         ed.entry.builder = ctx.args.is_true();
         tell_user!(ctx.writer, "Builder flag is now {}.\n", if ed.entry.builder {"set"} else {"unset"});
     }
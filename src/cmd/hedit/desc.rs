@@ -1,6 +1,6 @@
 //! HEdit/'desc' command.
 use async_trait::async_trait;
-use crate::{access_ed_entry, cmd::{Command, CommandCtx}, show_help, util::{ed::{edit_text, EdResult}, Editor}, validate_builder};
+use crate::{access_ed_entry, cmd::{Command, CommandCtx}, show_help, string::template, tell_user, util::{ed::{edit_text, EdResult}, Editor}, validate_builder};
 
 pub struct DescCommand;
 
@@ -16,6 +16,9 @@ impl Command for DescCommand {
             Ok(EdResult::ContentReady { text, verbose, dirty: true }) => {
                 let mut g = ctx.player.write().await;
                 g.hedit.set_description(&text);
+                if let Err(e) = template::check(&text) {
+                    tell_user!(ctx.writer, "<c red>Warning:</c> {}\n", e);
+                }
                 verbose
             },
             Ok(EdResult::NoChanges(true)) => true,
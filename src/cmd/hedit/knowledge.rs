@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use crate::{cmd::{help::HelpCommand, Command, CommandCtx}, tell_user, validate_builder};
+
+pub struct KnowledgeCommand;
+
+#[async_trait]
+impl Command for KnowledgeCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            return tell_user!(ctx.writer, "Requires knowledge: {}\n",
+                ctx.player.read().await.hedit.as_ref().unwrap().entry.requires_knowledge.as_deref().unwrap_or("-"));
+        }
+
+        if ctx.args.starts_with('?') {
+            let cmd = HelpCommand;
+            return cmd.exec({ctx.args = "hedit-knowledge"; ctx}).await;
+        }
+
+        let mut g = ctx.player.write().await;
+        let ed = g.hedit.as_mut().unwrap();
+        ed.checkpoint();
+        ed.entry.requires_knowledge = match ctx.args.trim() {
+            "none"|"-" => None,
+            id => Some(id.into())
+        };
+        match &ed.entry.requires_knowledge {
+            Some(id) => tell_user!(ctx.writer, "Entry now requires knowledge of '{}'.\n", id),
+            None => tell_user!(ctx.writer, "Entry is now common knowledge.\n"),
+        }
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use tokio::sync::RwLock;
-use crate::{help_reg_lock, cmd::{Command, CommandCtx, hedit::HeditState}, tell_user, traits::save::{DoesSave, SaveError}, validate_builder};
+use crate::{help_reg_lock, cmd::{Command, CommandCtx, hedit::HeditState}, tell_user, traits::save::{DoesSave, SaveError}, validate_area_builder, validate_builder};
 
 pub struct SaveCommand;
 
@@ -11,6 +11,10 @@ pub struct SaveCommand;
 impl Command for SaveCommand {
     async fn exec(&self, ctx: &mut CommandCtx<'_>) {
         validate_builder!(ctx);
+        let area_id = ctx.player.read().await.hedit.as_ref().and_then(|ed| ed.entry.area.clone());
+        if let Some(area_id) = area_id {
+            validate_area_builder!(ctx, area_id);
+        }
         
         if let Some(ref mut ed) = ctx.player.write().await.hedit {
             let g = ed.save().await;
@@ -3,7 +3,7 @@ use std::{collections::HashMap, sync::{Arc, OnceLock}};
 
 use async_trait::async_trait;
 use tokio::sync::RwLock;
-use crate::{cmd::{Command, CommandCtx}, show_help_if_needed, tell_user, util::Help};
+use crate::{cmd::{Command, CommandCtx}, show_help_if_needed, string::template::{render, TemplateCtx}, tell_user, util::Help};
 
 pub struct HelpCommand;
 
@@ -22,11 +22,11 @@ macro_rules! help_reg_lock {
 #[async_trait]
 impl Command for HelpCommand {
     async fn exec(&self, ctx: &mut CommandCtx<'_>) {
-        let (is_admin, is_builder) = {
+        let (is_admin, is_builder, known) = {
             let g = ctx.player.read().await;
             let a = g.access.is_admin();
             let b = g.access.is_builder();
-            (a,b)
+            (a,b, g.known_secrets.clone())
         };
 
         let (quick, args) = {
@@ -37,7 +37,7 @@ impl Command for HelpCommand {
                 let mut topics = Vec::new();
                 for entry_lock in h.0.values() {
                     if let Ok(e) = entry_lock.try_read() {
-                        if (!e.admin || is_admin) && (!e.builder || is_builder) {
+                        if e.visible_to(is_admin, is_builder, &known) {
                             topics.push(e.id.clone());
                         }
                     } else {
@@ -89,17 +89,20 @@ impl Command for HelpCommand {
                 return;
             }
             let help_entry = help_entry.unwrap();
-            let (admin_only, builder_only, desc) = {
+            let (visible, desc) = {
                 let g = help_entry.read().await;
-                let a = g.admin;
-                let b = g.builder;
+                let v = g.visible_to(is_admin, is_builder, &known);
                 let d = if quick { g.description.clone() } else { g.to_string() };
-                (a,b,d)
+                (v,d)
             };
             
-            if (!admin_only || is_admin) &&
-               (!builder_only || is_builder)
-            {
+            if visible {
+                drop(h);
+                let desc = {
+                    let p = ctx.player.read().await;
+                    let w = ctx.world.read().await;
+                    render(&desc, &TemplateCtx::new(&w, &p).await)
+                };
                 return if quick {
                     tell_user!(ctx.writer, "{}\n", desc);
                 } else {
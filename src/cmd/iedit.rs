@@ -0,0 +1,126 @@
+//! Item Editor - IEdit, for authoring item [Blueprint]s.
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use crate::{cmd::{Command, CommandCtx}, item::blueprint::{BLUEPRINTS, Blueprint, BlueprintKind}, string::slug::as_id, tell_user, traits::IdentityQuery, util::{clientstate::EditorMode, history::EditHistory, Editor}, validate_blueprint_author, validate_builder, ClientState};
+
+pub mod capacity;
+pub mod desc;
+pub mod flags;
+pub mod save;
+pub mod spawn;
+pub mod title;
+pub mod r#type;
+
+pub struct IeditCommand;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IeditState {
+    pub entry: Blueprint,
+    pub dirty: bool,
+    #[serde(skip, default)]
+    pub history: EditHistory<Blueprint>,
+}
+
+impl IeditState {
+    /// Remember the entry as-is, about to be changed.
+    pub fn checkpoint(&mut self) {
+        self.history.record(&self.entry);
+        self.dirty = true;
+    }
+}
+
+#[async_trait]
+impl Command for IeditCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() && ctx.player.read().await.iedit.is_none() {
+            return tell_user!(ctx.writer,
+                "BLUEPRINT-ID missing and no previous IEdit session stored.\n\
+                Which blueprint you want to edit? '<c yellow>iedit list</c>' shows the known ones.\n");
+        }
+
+        if ctx.args == "list" {
+            let list = BLUEPRINTS.read().await.list().iter()
+                .map(|bp| format!("  <c yellow>{:<20}</c> {:<9} {}\n", bp.id, bp.kind, bp.title))
+                .collect::<String>();
+            if list.is_empty() {
+                return tell_user!(ctx.writer, "No blueprints yet.\n");
+            }
+            return tell_user!(ctx.writer, "<c yellow>-[ BLUEPRINTS ]-</c>\n{}", list);
+        }
+
+        {
+            let mut g = ctx.player.write().await;
+            if let Some(ed) = g.iedit.as_ref() {
+                if !ctx.args.is_empty() && ed.entry.id() != ctx.args {
+                    if ed.dirty {
+                        return tell_user!(ctx.writer, "<c red>Warning!</c> Unsaved edits - '<c yellow>save</c>' or '<c yellow>abort</c>' first.\n");
+                    }
+                } else {
+                    let id = ed.entry.id.clone();
+                    g.push_state(ClientState::Editing { mode: EditorMode::Item });
+                    return tell_user!(ctx.writer, "Resuming IEdit('{}') session.\n", id);
+                }
+            }
+        }
+
+        let id = match as_id(ctx.args) {
+            Ok(id) => id,
+            Err(_) => return tell_user!(ctx.writer, "<c red>Error:</c> '{}' won't do as a blueprint ID.\n", ctx.args)
+        };
+        validate_blueprint_author!(ctx, id);
+
+        let existing = BLUEPRINTS.read().await.get(&id).cloned();
+        let mut g = ctx.player.write().await;
+        let (entry, dirty) = match existing {
+            Some(bp) => {
+                log::info!("Player '{}' editing existing blueprint '{}'", g.id(), id);
+                (bp, false)
+            },
+            None => {
+                log::info!("Player '{}' editing new blueprint '{}'", g.id(), id);
+                let mut bp = Blueprint::new(&id);
+                bp.author = g.id().into();
+                (bp, true)
+            }
+        };
+        tell_user!(ctx.writer, "{}", fmt_specs(&entry));
+        g.iedit = Some(IeditState { entry, dirty, history: EditHistory::default() });
+        g.push_state(ClientState::Editing { mode: EditorMode::Item });
+    }
+}
+
+/// Pretty-print the specs of `bp` — only those which matter for its kind.
+pub(crate) fn fmt_specs(bp: &Blueprint) -> String {
+    let mut out = format!("<c yellow>-[ {} ]-</c>\n  Type:  <c cyan>{}</c>\n  Title: <c cyan>{}</c>\n", bp.id, bp.kind, bp.title);
+    if !bp.author.is_empty() {
+        out.push_str(&format!("  Author: <c cyan>{}</c>\n", bp.author));
+    }
+    match bp.kind {
+        BlueprintKind::Container => out.push_str(&format!("  Capacity: <c cyan>{}</c>\n", bp.capacity)),
+        BlueprintKind::Tool => out.push_str(&format!("  Single use: {}, light: {}\n", yes_no(bp.single_use), yes_no(bp.light))),
+        BlueprintKind::Key => out.push_str(&format!("  One time: {}\n", yes_no(bp.one_time))),
+        _ => ()
+    }
+    out
+}
+
+fn yes_no(b: bool) -> &'static str {
+    if b {"<c green>yes</c>"} else {"<c gray>no</c>"}
+}
+
+impl Editor for IeditState {
+    fn set_description(&mut self, desc: &str) {
+        self.checkpoint();
+        self.entry.description = desc.into();
+    }
+}
+
+impl Editor for Option<IeditState> {
+    fn set_description(&mut self, desc: &str) {
+        if let Some(state) = self {
+            state.set_description(desc);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! How many items a container [Blueprint][crate::item::blueprint::Blueprint] holds.
+use async_trait::async_trait;
+use crate::{access_ed_entry, cmd::{Command, CommandCtx}, item::blueprint::{BlueprintKind, MAX_BP_CAPACITY}, show_help_if_needed, tell_user, validate_builder};
+
+pub struct CapacityCommand;
+
+#[async_trait]
+impl Command for CapacityCommand {
+    /// IEdit 'capacity'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            let (capacity, kind) = {
+                let p = ctx.player.read().await;
+                let bp = &p.iedit.as_ref().unwrap().entry;
+                (bp.capacity, bp.kind)
+            };
+            tell_user!(ctx.writer, "Holds up to <c cyan>{}</c> item(s).\n", capacity);
+            if kind != BlueprintKind::Container {
+                tell_user!(ctx.writer, "<c yellow>FYI:</c> capacity matters only for containers.\n");
+            }
+            return;
+        }
+        show_help_if_needed!(ctx, "iedit-capacity");
+
+        let capacity = match ctx.args.trim().parse::<usize>() {
+            Ok(n) if (1..=MAX_BP_CAPACITY).contains(&n) => n,
+            _ => return tell_user!(ctx.writer, "<c red>Error:</c> Capacity is a number between 1 and {}.\n", MAX_BP_CAPACITY)
+        };
+        if access_ed_entry!(ctx, iedit).capacity != capacity {
+            let mut g = ctx.player.write().await;
+            let ed = g.iedit.as_mut().unwrap();
+            ed.checkpoint();
+            ed.entry.capacity = capacity;
+        }
+
+        CapacityCommand.exec({ctx.args = ""; ctx}).await;
+    }
+}
@@ -0,0 +1,64 @@
+//! Item flag control.
+use async_trait::async_trait;
+
+use crate::{access_ed_entry, cmd::{Command, CommandCtx}, item::blueprint::Blueprint, show_help_if_needed, tell_user, validate_builder};
+
+pub struct FlagsCommand;
+
+/// The flags there are, along with what type of item they matter for.
+const FLAGS: [(&str, &str); 3] = [("one-time", "key"), ("single-use", "tool"), ("light", "tool")];
+
+fn flag_mut<'a>(bp: &'a mut Blueprint, name: &str) -> Option<&'a mut bool> {
+    match name {
+        "one-time" => Some(&mut bp.one_time),
+        "single-use" => Some(&mut bp.single_use),
+        "light" => Some(&mut bp.light),
+        _ => None
+    }
+}
+
+#[async_trait]
+impl Command for FlagsCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            let mut output = "<c yellow>-[ FLAGS ]-</c>\n".to_string();
+            let mut bp = access_ed_entry!(ctx, iedit).clone();
+            for (name, kind) in FLAGS {
+                let mark = if *flag_mut(&mut bp, name).unwrap() {"<c green>+</c>"} else {"<c gray>-</c>"};
+                output.push_str(&format!("  {} {:<10} <c gray>({})</c>\n", mark, name, kind));
+            }
+            tell_user!(ctx.writer, output);
+            return;
+        }
+        show_help_if_needed!(ctx, "iedit-flags");
+
+        let mut req_change = access_ed_entry!(ctx, iedit).clone();
+        for part in ctx.args.split_whitespace() {
+            let (set, name) = match (part.strip_prefix('+'), part.strip_prefix('-')) {
+                (Some(name), _) => (true, name),
+                (_, Some(name)) => (false, name),
+                _ => (true, part)
+            };
+            let Some(flag) = flag_mut(&mut req_change, &name.to_lowercase()) else {
+                tell_user!(ctx.writer, "<c red>Error:</c> No such flag as '{}'.\n<c red>Edit aborted</c> due errorneous input.\n", name);
+                return;
+            };
+            *flag = set;
+        }
+
+        {
+            let mut g = ctx.player.write().await;
+            let ed = g.iedit.as_mut().unwrap();
+            if (req_change.one_time, req_change.single_use, req_change.light)
+            == (ed.entry.one_time, ed.entry.single_use, ed.entry.light) {
+                return tell_user!(ctx.writer, "Flags stay as they were.\n");
+            }
+            ed.checkpoint();
+            ed.entry = req_change;
+        }
+        tell_user!(ctx.writer, "Flags updated.\n");
+        FlagsCommand.exec({ctx.args = ""; ctx}).await;
+    }
+}
@@ -0,0 +1,30 @@
+//! IEdit/'save' — save [Blueprint][crate::item::blueprint::Blueprint] edits.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, item::blueprint::BLUEPRINTS, tell_user, traits::{IdentityQuery, save::DoesSave}, validate_blueprint_author, validate_builder};
+
+pub struct SaveCommand;
+
+#[async_trait]
+impl Command for SaveCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+        let Some(mut entry) = ctx.player.read().await.iedit.as_ref().map(|ed| ed.entry.clone()) else {
+            log::debug!("Where'd the stored IEdit state go for '{}'?!", ctx.player.read().await.id());
+            return tell_user!(ctx.writer, "You could've sworn you were editing a blueprint, but…\n");
+        };
+        // someone else might've claimed the ID meanwhile.
+        validate_blueprint_author!(ctx, entry.id);
+
+        if let Err(e) = entry.save().await {
+            log::error!("FATAL: save error '{e:?}'");
+            return tell_user!(ctx.writer, "Something went awry…\n");
+        }
+        BLUEPRINTS.write().await.insert(entry);
+
+        if let Some(ed) = ctx.player.write().await.iedit.as_mut() {
+            ed.dirty = false;
+        }
+        tell_user!(ctx.writer, "Edits stored.\n");
+    }
+}
@@ -0,0 +1,29 @@
+//! IEdit/'spawn' — craft an item off of the blueprint (as it stands) for a test drive.
+use async_trait::async_trait;
+
+use crate::{access_ed_entry, cmd::{Command, CommandCtx}, item::inventory::Storage, player::journal::JournalEntry, tell_user, traits::IdentityQuery, validate_builder};
+
+pub struct SpawnCommand;
+
+#[async_trait]
+impl Command for SpawnCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        let item = access_ed_entry!(ctx, iedit).instantiate();
+        let (id, title) = (item.id().to_string(), item.title().to_string());
+        let gained = JournalEntry::Gained(item.clone());
+        let res = {
+            let mut p = ctx.player.write().await;
+            let res = p.inventory.try_insert(item);
+            if res.is_ok() {
+                p.journal(gained);
+            }
+            res
+        };
+        match res {
+            Ok(()) => tell_user!(ctx.writer, "'{}' <c gray>({})</c> appears in your inventory.\n", title, id),
+            Err(e) => tell_user!(ctx.writer, "<c red>Error:</c> {}.\n", e)
+        }
+    }
+}
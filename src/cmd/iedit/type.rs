@@ -0,0 +1,38 @@
+//! The general type of item a [Blueprint][crate::item::blueprint::Blueprint] makes.
+use async_trait::async_trait;
+use crate::{access_ed_entry, cmd::{Command, CommandCtx}, item::blueprint::BlueprintKind, show_help_if_needed, tell_user, validate_builder};
+
+pub struct TypeCommand;
+
+#[async_trait]
+impl Command for TypeCommand {
+    /// IEdit 'type'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            let kind = access_ed_entry!(ctx, iedit).kind;
+            let mut output = "<c yellow>-[ TYPE ]-</c>\n".to_string();
+            for k in BlueprintKind::ALL {
+                let mark = if k == kind {"<c green>*</c>"} else {" "};
+                output.push_str(&format!("  {} {}\n", mark, k));
+            }
+            return tell_user!(ctx.writer, output);
+        }
+        show_help_if_needed!(ctx, "iedit-type");
+
+        let Ok(kind) = BlueprintKind::try_from(ctx.args.trim()) else {
+            return tell_user!(ctx.writer, "<c red>Error:</c> No such item type as '{}'.\n", ctx.args.trim());
+        };
+        {
+            let mut g = ctx.player.write().await;
+            let ed = g.iedit.as_mut().unwrap();
+            if ed.entry.kind != kind {
+                ed.checkpoint();
+                ed.entry.kind = kind;
+            }
+        }
+
+        TypeCommand.exec({ctx.args = ""; ctx}).await;
+    }
+}
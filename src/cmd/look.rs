@@ -1,8 +1,8 @@
 //! Looking around, looking at, looking into…
-use std::{collections::HashMap, fmt::Display};
+use std::{collections::{HashMap, HashSet}, fmt::Display};
 
 use async_trait::async_trait;
-use crate::{cmd::{Command, CommandCtx, hedit::title}, do_in_current_room, item::inventory::Storage, tell_user, traits::{Description, IdentityQuery}};
+use crate::{cmd::{Command, CommandCtx, hedit::title}, do_in_current_room, item::inventory::Storage, string::template::{render, TemplateCtx}, tell_user, traits::{Description, IdentityQuery, mob::IsMob}, world::{World, minimap::{self, MINIMAP_RADIUS}, room::flags::RoomFlag}};
 
 pub struct LookCommand;
 
@@ -89,6 +89,10 @@ async fn look_at_or_into(ctx: &mut CommandCtx<'_>, spec: LookSpecifier, target:
                     }
                 }
             }
+            // mobs…
+            if let Some(m) = lock.find_mob(&target_lc).filter(|m| !m.core.invis()) {
+                tell_user!(ctx.writer, "<c purple>{}</c>\n{}\n", m.title(), m.description());
+            }
             // items & other stuff…
             for i in lock.contents.items().values() {
                 if i.id().contains(&target_lc) {
@@ -109,13 +113,46 @@ async fn look_at_or_into(ctx: &mut CommandCtx<'_>, spec: LookSpecifier, target:
 
 /// The looking glass… used by e.g. 'look' command, etc.
 pub(crate) async fn look_at_current_room(ctx: &mut CommandCtx<'_>) {
+    let (map, is_builder, has_light, sky, drafts, (world_title, time, weather)) = {
+        let p = ctx.player.read().await;
+        let w = ctx.world.read().await;
+        let map = if p.automap {
+            minimap::render(&w, &p.location, MINIMAP_RADIUS, Some(&p)).await
+        } else { None };
+        let tmpl = (w.title().to_string(), w.time(), w.weather_at(&p.location).await);
+        (map, p.access.is_builder(), p.has_light(), sky_over(&w, &p.location).await, draft_exits(&w, &p.location).await, tmpl)
+    };
     do_in_current_room!(ctx, |room| {
         let r = room.read().await;
-        let mut desc = format!(
-            "<c yellow>{}</c>\n\n{}\n\n",
-            r.title(),
-            r.description()
-        );
+        let mut desc = map.map(|m| format!("{m}\n")).unwrap_or_default();
+
+        // builders need not stumble around in the dark.
+        if r.has_flag(RoomFlag::Dark) && !has_light && !is_builder {
+            desc.push_str("It is pitch black… you can't see a thing.\n\n");
+            tell_user!(ctx.writer, &desc);
+            return;
+        }
+
+        desc.push_str(&format!(
+            "<c yellow>{}</c>\n",
+            r.title()
+        ));
+        if is_builder {
+            let mut flags = r.flags.iter().map(|f| f.as_str()).collect::<Vec<_>>();
+            flags.sort();
+            desc.push_str(&format!("<c gray>[{}] [sector: {}] [flags: {}]</c>{}\n",
+                r.id(), r.sector, if flags.is_empty() {"none".into()} else {flags.join(", ")},
+                if drafts.contains(&r.id) {" <c red>[draft]</c>"} else if r.revision.is_some() {" <c red>[revision pending]</c>"} else {""}));
+        }
+        // descriptions may vary by viewer, time, weather, etc.
+        let text = {
+            let p = ctx.player.read().await;
+            render(r.description(), &TemplateCtx { player: &p, world_title: &world_title, time, weather })
+        };
+        desc.push_str(&format!("\n{}\n\n", text));
+        if let Some(sky) = sky {
+            desc.push_str(&format!("<c cyan>{}</c>\n\n", sky));
+        }
 
         /* ITEMS ON FLOOR */{
             if !r.is_empty() {
@@ -136,6 +173,20 @@ pub(crate) async fn look_at_current_room(ctx: &mut CommandCtx<'_>) {
             }
         }
 
+        /* MOBS */{
+            let mut mobs = r.mobs.values()
+                .filter(|m| !m.core.invis())
+                .map(|m| m.title())
+                .collect::<Vec<&str>>();
+            if !mobs.is_empty() {
+                mobs.sort();
+                for m in mobs {
+                    desc.push_str(&format!("    <c purple>{m}</c>\n"));
+                }
+                desc.push_str("\n");
+            }
+        }
+
         /* PEOPLE */{
             if !r.players.is_empty() {
                 for p in r.players.keys() {
@@ -146,9 +197,16 @@ pub(crate) async fn look_at_current_room(ctx: &mut CommandCtx<'_>) {
         }
 
         /* EXITS */{
-            if !r.exits.is_empty() {
+            let p = ctx.player.read().await;
+            let exits: Vec<String> = r.exits.iter()
+                .filter(|(d, e)| e.visible_to(&r.id, d, &p) && (is_builder || !drafts.contains(&e.destination)))
+                .map(|(d, e)| match &e.hidden {
+                    Some(c) if is_builder => format!("{d}<c gray>({})</c>", if c.secret {"secret"} else {"hidden"}),
+                    _ => d.to_string()
+                })
+                .collect();
+            if !exits.is_empty() {
                 desc.push_str("<c green>Exits:</c> ");
-                let exits: Vec<String> = r.exits.keys().map(|d| format!("{:?}", d).to_lowercase()).collect();
                 desc.push_str(&exits.join(", "));
                 desc.push_str("\n\n");
             }
@@ -159,6 +217,35 @@ pub(crate) async fn look_at_current_room(ctx: &mut CommandCtx<'_>) {
     });
 }
 
+/// Which of `room_id` and the rooms its exits lead to are drafts.
+async fn draft_exits(world: &World, room_id: &str) -> HashSet<String> {
+    let mut ids = match world.rooms.get(room_id) {
+        Some(r) => r.read().await.exits.values().map(|e| e.destination.clone()).collect::<HashSet<_>>(),
+        None => return HashSet::new()
+    };
+    ids.insert(room_id.into());
+    let mut drafts = HashSet::new();
+    for id in ids {
+        if world.is_draft(&id).await {
+            drafts.insert(id);
+        }
+    }
+    drafts
+}
+
+/// What the sky looks like above `room_id` — if it's outdoors, that is.
+async fn sky_over(world: &World, room_id: &str) -> Option<String> {
+    let area_id = {
+        let r = world.rooms.get(room_id)?.read().await;
+        if !r.is_outdoors() {
+            return None;
+        }
+        r.parent_id.clone()
+    };
+    let weather = world.areas.get(&area_id)?.read().await.weather;
+    Some(weather.describe(world.time().is_daytime()).into())
+}
+
 #[cfg(test)]
 mod cmd_look_tests {
     use std::sync::Arc;
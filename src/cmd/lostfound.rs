@@ -0,0 +1,118 @@
+//! Lost-and-found vault management.
+//!
+//! Items rescued from The Void linger in the [vault][crate::item::vault::VAULT]
+//! until they're returned, dropped somewhere, or they expire.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, item::{Item, inventory::Storage, vault::{VaultEntry, VAULT}}, player::journal::JournalEntry, show_help, show_help_if_needed, tell_user, traits::{IdentityQuery, Owned, save::DoesSave}, util::timestamp::{fmt_span, unix_now}, validate_admin};
+
+pub struct LostfoundCommand;
+
+#[async_trait]
+impl Command for LostfoundCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_admin!(ctx);
+        show_help_if_needed!(ctx, "lostfound");
+
+        let args = ctx.args.split_whitespace().collect::<Vec<&str>>();
+        let now = unix_now();
+        match args[0].to_lowercase().as_str() {
+            "list" => {
+                let vault = VAULT.read().await;
+                let entries = vault.list(args.get(1).copied());
+                if entries.is_empty() {
+                    tell_user!(ctx.writer, "Nothing in the vault{}.\n", args.get(1).map(|o| format!(" for '{o}'")).unwrap_or_default());
+                    return;
+                }
+                let mut out = String::new();
+                for e in entries {
+                    out.push_str(&format!("  <c yellow>{}</c> '{}' — owner <c cyan>{}</c>, expires in {}\n",
+                        e.item.id(), e.item.title(), e.item.original_owner(), fmt_span(e.expires_in(now))));
+                }
+                tell_user!(ctx.writer, "{}", out);
+            },
+
+            "show" => {
+                let Some(id) = args.get(1) else { show_help!(ctx, "lostfound"); };
+                let vault = VAULT.read().await;
+                let Some(e) = vault.find_id(id).and_then(|id| vault.get(&id)) else {
+                    tell_user!(ctx.writer, "No '{}' in the vault.\n", id);
+                    return;
+                };
+                tell_user!(ctx.writer,
+                    "<c yellow>{}</c> '{}'\n  Owner: {} (originally {})\n  Reason: {}\n  Stored {} ago, expires in {}.\n",
+                    e.item.id(), e.item.title(), e.item.owner(), e.item.original_owner(), e.reason,
+                    fmt_span(now.saturating_sub(e.stored_at)), fmt_span(e.expires_in(now)));
+            },
+
+            "return" => {
+                let Some(id) = args.get(1) else { show_help!(ctx, "lostfound"); };
+                let Some(entry) = take_entry(id).await else {
+                    tell_user!(ctx.writer, "No '{}' in the vault.\n", id);
+                    return;
+                };
+                let owner_id = entry.item.original_owner().to_string();
+                let Some(owner) = ctx.world.read().await.find_player(&owner_id) else {
+                    tell_user!(ctx.writer, "'{}' is not around to receive it. Try again when they're online.\n", owner_id);
+                    VAULT.write().await.restore(entry);
+                    return;
+                };
+                let VaultEntry { mut item, reason, stored_at } = entry;
+                let _ = item.set_owner(&owner_id);
+                let item_id = item.id().to_string();
+                let gained = JournalEntry::Gained(item.clone());
+                let mut p = owner.write().await;
+                if let Err(e) = p.inventory.try_insert(item) {
+                    tell_user!(ctx.writer, "Could not return '{}': {}\n", item_id, e);
+                    VAULT.write().await.restore(VaultEntry { item: Item::from(e), reason, stored_at });
+                    return;
+                }
+                p.journal(gained);
+                drop(p);
+                log::info!("Lost-and-found: '{}' returned to '{}'.", item_id, owner_id);
+                tell_user!(ctx.writer, "'{}' returned to '{}'.\n", item_id, owner_id);
+                save_vault().await;
+            },
+
+            "drop" => {
+                let Some(id) = args.get(1) else { show_help!(ctx, "lostfound"); };
+                let room_id = match args.get(2) {
+                    Some(r) => r.to_string(),
+                    None => ctx.player.read().await.location.clone()
+                };
+                let Some(room) = ctx.world.read().await.rooms.get(&room_id).cloned() else {
+                    tell_user!(ctx.writer, "No such room as '{}'.\n", room_id);
+                    return;
+                };
+                let Some(VaultEntry { item, reason, stored_at }) = take_entry(id).await else {
+                    tell_user!(ctx.writer, "No '{}' in the vault.\n", id);
+                    return;
+                };
+                let item_id = item.id().to_string();
+                if let Err(e) = room.write().await.try_insert(item) {
+                    tell_user!(ctx.writer, "Could not drop '{}' into '{}': {}\n", item_id, room_id, e);
+                    VAULT.write().await.restore(VaultEntry { item: Item::from(e), reason, stored_at });
+                    return;
+                }
+                log::info!("Lost-and-found: '{}' dropped into '{}'.", item_id, room_id);
+                tell_user!(ctx.writer, "'{}' dropped into '{}'.\n", item_id, room_id);
+                save_vault().await;
+            },
+
+            _ => { show_help!(ctx, "lostfound"); }
+        }
+    }
+}
+
+/// Take an entry out of the vault by (partial) `id`.
+async fn take_entry(id: &str) -> Option<VaultEntry> {
+    let mut vault = VAULT.write().await;
+    let id = vault.find_id(id)?;
+    vault.take(&id)
+}
+
+async fn save_vault() {
+    if let Err(e) = VAULT.write().await.save().await {
+        log::error!("Failed to save lost-and-found vault: {:?}", e);
+    }
+}
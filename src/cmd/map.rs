@@ -0,0 +1,42 @@
+//! A map of the surroundings.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, show_help, tell_user, world::minimap::{self, MINIMAP_RADIUS}};
+
+pub struct MapCommand;
+
+#[async_trait]
+impl Command for MapCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        if ctx.args.starts_with('?') {
+            show_help!(ctx, "map");
+        }
+
+        let args = ctx.args.split_whitespace().collect::<Vec<&str>>();
+        match args.first().map(|a| a.to_lowercase()).as_deref() {
+            None => {
+                let p = ctx.player.read().await;
+                let map = minimap::render(&*ctx.world.read().await, &p.location, MINIMAP_RADIUS, Some(&p)).await;
+                drop(p);
+                match map {
+                    Some(m) => tell_user!(ctx.writer, "{}\n<c gray>@ you, ^ v % up/down, * elsewhere, + door, ? twisty</c>\n", m),
+                    None => tell_user!(ctx.writer, "You're… nowhere? Nothing to map.\n"),
+                }
+            },
+
+            Some("auto") => {
+                let mut p = ctx.player.write().await;
+                p.automap = match args.get(1).map(|a| a.to_lowercase()).as_deref() {
+                    Some("on") => true,
+                    Some("off") => false,
+                    None => !p.automap,
+                    _ => { drop(p); show_help!(ctx, "map"); }
+                };
+                p.inc_act_count();
+                tell_user!(ctx.writer, "Map along with 'look' is now {}.\n", if p.automap {"on"} else {"off"});
+            },
+
+            _ => { show_help!(ctx, "map"); }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Mob wrangling — spawn and purge live mobs, list the templates.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, mob::template::{MOB_TEMPLATES, MobRegistry}, show_help, show_help_if_needed, tell_user, traits::IdentityQuery, validate_builder};
+
+pub struct MobCommand;
+
+#[async_trait]
+impl Command for MobCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+        show_help_if_needed!(ctx, "mob");
+
+        let args = ctx.args.split_whitespace().collect::<Vec<&str>>();
+        match args[0].to_lowercase().as_str() {
+            "list" => {
+                let reg = MOB_TEMPLATES.read().await;
+                let list = reg.list();
+                if list.is_empty() {
+                    tell_user!(ctx.writer, "No mob templates around.\n");
+                    return;
+                }
+                let mut out = String::new();
+                for m in list {
+                    out.push_str(&format!("  <c yellow>{}</c> — {}\n", m.id(), m.title()));
+                }
+                tell_user!(ctx.writer, "{}", out);
+            },
+
+            "spawn" => {
+                let Some(template) = args.get(1) else { show_help!(ctx, "mob"); };
+                let room_id = match args.get(2) {
+                    Some(r) => r.to_string(),
+                    None => ctx.player.read().await.location.clone()
+                };
+                let Some(room) = ctx.world.read().await.rooms.get(&room_id).cloned() else {
+                    tell_user!(ctx.writer, "No such room as '{}'.\n", room_id);
+                    return;
+                };
+                let Some(mob) = MOB_TEMPLATES.read().await.spawn(template) else {
+                    tell_user!(ctx.writer, "No such mob template as '{}'.\n", template);
+                    return;
+                };
+                let (id, title) = (mob.id().to_string(), mob.title().to_string());
+                room.write().await.add_mob(mob);
+                log::info!("Mob '{}' spawned into '{}'.", id, room_id);
+                tell_user!(ctx.writer, "'{}' ({}) spawned into '{}'.\n", title, id, room_id);
+            },
+
+            "purge" => {
+                let Some(target) = args.get(1) else { show_help!(ctx, "mob"); };
+                let location = ctx.player.read().await.location.clone();
+                let Some(room) = ctx.world.read().await.rooms.get(&location).cloned() else {
+                    tell_user!(ctx.writer, "You're nowhere. Nothing to purge.\n");
+                    return;
+                };
+                let mut room = room.write().await;
+                let purged = if *target == "all" {
+                    let n = room.mobs.len();
+                    room.mobs.clear();
+                    n
+                } else {
+                    let Some(id) = room.find_mob(target).map(|m| m.id().to_string()) else {
+                        tell_user!(ctx.writer, "No '{}' around here.\n", target);
+                        return;
+                    };
+                    room.mobs.remove(&id);
+                    1
+                };
+                log::info!("{} mob(s) purged from '{}'.", purged, location);
+                tell_user!(ctx.writer, "{} mob{} purged.\n", purged, if purged == 1 {""} else {"s"});
+            },
+
+            "reload" => {
+                match MobRegistry::load().await {
+                    Ok(reg) => {
+                        let n = reg.list().len();
+                        *MOB_TEMPLATES.write().await = reg;
+                        tell_user!(ctx.writer, "{} mob template{} loaded.\n", n, if n == 1 {""} else {"s"});
+                    },
+                    Err(e) => tell_user!(ctx.writer, "Could not reload mob templates: {:?}\n", e),
+                }
+            },
+
+            _ => { show_help!(ctx, "mob"); }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Find one's way — the shortest route to some room.
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, show_help, show_help_if_needed, tell_user, util::direction::Direction, world::path::{PathOptions, find_path}};
+
+pub struct PathCommand;
+
+#[async_trait]
+impl Command for PathCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        show_help_if_needed!(ctx, "path");
+
+        let args = ctx.args.split_whitespace().collect::<Vec<&str>>();
+        let Some(target) = args.first() else { show_help!(ctx, "path"); };
+        let from = ctx.player.read().await.location.clone();
+
+        // Doors can be opened, locks... not necessarily.
+        // …and builders know the hidden ways (and the drafts), too — others just the ones they've found.
+        let (through_hidden, known_exits) = {
+            let p = ctx.player.read().await;
+            (p.access.is_builder(), p.known_secrets.list().into_iter()
+                .filter(|(id, _)| id.starts_with("exit:"))
+                .map(|(id, _)| id.clone())
+                .collect::<HashSet<_>>())
+        };
+        let mut opts = PathOptions { through_closed: true, through_hidden, through_drafts: through_hidden, known_exits, ..Default::default() };
+        if args.get(1).is_some_and(|a| a.eq_ignore_ascii_case("local")) {
+            let w = ctx.world.read().await;
+            if let Some(room) = w.rooms.get(&from) {
+                opts.areas = Some(HashSet::from([room.read().await.parent_id.clone()]));
+            }
+        }
+
+        let path = find_path(&*ctx.world.read().await, &from, target, &opts).await;
+        match path {
+            Ok(dirs) if dirs.is_empty() => tell_user!(ctx.writer, "You're already there.\n"),
+            Ok(dirs) => tell_user!(ctx.writer, "Path to '{}' ({} step{}): {}\n",
+                target, dirs.len(), if dirs.len() == 1 {""} else {"s"}, fmt_path(&dirs)),
+            Err(e) => tell_user!(ctx.writer, "{}.\n", e),
+        }
+    }
+}
+
+/// Squash runs of the same direction, e.g. "2×east, north".
+pub(crate) fn fmt_path(dirs: &[Direction]) -> String {
+    let mut out: Vec<(usize, &Direction)> = vec![];
+    for d in dirs {
+        match out.last_mut() {
+            Some((n, last)) if *last == d => *n += 1,
+            _ => out.push((1, d)),
+        }
+    }
+    out.iter()
+        .map(|(n, d)| if *n > 1 { format!("{n}×{d}") } else { d.to_string() })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
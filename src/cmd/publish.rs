@@ -0,0 +1,57 @@
+//! Publish drafts — put them live for everyone to see.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, show_help_if_needed, tell_user, traits::{IdentityQuery, save::DoesSave}, validate_builder_lead, world::draft::{publish_area, publish_room}};
+
+pub struct PublishCommand;
+
+#[async_trait]
+impl Command for PublishCommand {
+    /// 'publish [room|area] <id|here>'
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder_lead!(ctx);
+        show_help_if_needed!(ctx, "publish");
+
+        let (scope, id) = match ctx.args.trim().split_once(' ') {
+            Some((scope @ ("room"|"area"), id)) => (Some(scope), id.trim()),
+            _ => (None, ctx.args.trim())
+        };
+        let id = match id {
+            "this"|"here" => ctx.player.read().await.location.clone(),
+            other => other.to_string()
+        };
+
+        let mut w = ctx.world.write().await;
+        // rooms win over areas, unless told otherwise.
+        let res = match scope {
+            Some("area") => publish_area(&mut w, &id).await,
+            Some(_) => publish_room(&mut w, &id).await,
+            None if w.rooms.contains_key(&id) => publish_room(&mut w, &id).await,
+            None => publish_area(&mut w, &id).await
+        };
+        let published = match res {
+            Ok(p) => p,
+            Err(e) => return tell_user!(ctx.writer, "<c red>Error:</c> {}.\n", e)
+        };
+        for room_id in &published.rooms {
+            let Some(room) = w.rooms.get(room_id) else { continue };
+            if let Err(e) = room.write().await.save().await {
+                log::error!("Saving published room '{}' failed: {e:?}", room_id);
+            }
+        }
+        let area = published.area.as_ref().and_then(|a| w.areas.get(a));
+        if let Some(Err(e)) = match area {
+            Some(area) => Some(area.write().await.save().await),
+            None => None
+        } {
+            log::error!("Saving published area '{}' failed: {e:?}", id);
+        }
+        drop(w);
+
+        log::info!("'{}' published by '{}': area {:?}, rooms {:?}", id, ctx.player.read().await.id(), published.area, published.rooms);
+        match published.area {
+            Some(area_id) => tell_user!(ctx.writer, "Area '{}' is live, along with {} room(s).\n", area_id, published.rooms.len()),
+            None => tell_user!(ctx.writer, "Published: {}.\n", published.rooms.join(", "))
+        }
+    }
+}
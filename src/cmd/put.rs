@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use crate::{cmd::{Command, CommandCtx}, force_item_to_player, item::{inventory::Storage, Item, ItemError}, show_help, tell_user, traits::IdentityQuery};
+use crate::{cmd::{Command, CommandCtx}, force_item_to_player, item::{inventory::Storage, Item, ItemError}, player::journal::JournalEntry, show_help, tell_user, traits::IdentityQuery};
 
 pub struct PutCommand;
 
@@ -13,6 +13,7 @@ impl Command for PutCommand {
         let (what, where_to) = (args[0], args[1]);
         let mut p = ctx.player.write().await;
         if let Ok(item) = p.inventory.take_out(what) {
+            p.journal(JournalEntry::Lost(item.id().into()));
             drop(p);// prevent deadlocks
             match where_to {
                 "ground" => {
@@ -0,0 +1,53 @@
+//! Clone a [Room] — or a room template — into a new room next door.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx, dig::{create_and_link_room, validate_args}, translocate::translocate}, show_help, show_help_if_needed, tell_user, validate_area_builder, validate_builder, world::room::{Room, template::ROOM_TEMPLATES}};
+
+pub struct RcloneCommand;
+
+#[async_trait]
+impl Command for RcloneCommand {
+    /// 'rclone <room|here|template> <dir> <new-id>'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+        show_help_if_needed!(ctx, "rclone");
+
+        let location = ctx.player.read().await.location.clone();
+        let area_id = {
+            let w = ctx.world.read().await;
+            match w.rooms.get(&location) {
+                Some(r) => r.read().await.parent_id.clone(),
+                None => String::new()
+            }
+        };
+        validate_area_builder!(ctx, area_id);
+
+        let Some((source, rest)) = ctx.args.split_once(' ') else { show_help!(ctx, "rclone"); };
+        let source = match source {
+            "this"|"here" => location.clone(),
+            other => other.to_string()
+        };
+        let Some((dir, id)) = validate_args({ctx.args = rest.trim(); ctx}).await else { return };
+        let id = id.to_string();
+
+        // Rooms first, templates second.
+        let original = ctx.world.read().await.rooms.get(&source).cloned();
+        let (room, template) = match original {
+            Some(original) => {
+                let mut room = Room::blank(Some(&id));
+                room.shallow_copy(&*original.read().await);
+                // the exits lead elsewhere, though…
+                room.exits.clear();
+                (room, None)
+            },
+            None => match ROOM_TEMPLATES.read().await.get(&source) {
+                Some(t) => (t.instantiate(&id), Some(t.clone())),
+                None => return tell_user!(ctx.writer, "No room or template called '{}'.\n", source)
+            }
+        };
+
+        if create_and_link_room(ctx, dir, room, template.as_ref()).await {
+            let _ = translocate(ctx.world, Some(location), id, ctx.player.clone()).await;
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Delete a [Room][crate::world::room::Room] — after confirmation.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, show_help_if_needed, tell_user, traits::IdentityQuery, validate_area_builder, validate_builder, world::room::delete::{execute, fmt_plan, plan}};
+
+pub struct RdeleteCommand;
+
+#[async_trait]
+impl Command for RdeleteCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+        show_help_if_needed!(ctx, "rdelete");
+
+        let (id, confirmed) = match ctx.args.split_once(' ') {
+            Some((id, "confirm")) => (id, true),
+            Some(_) => return tell_user!(ctx.writer, "Use '<c yellow>rdelete ROOM-ID</c>' and then '<c yellow>rdelete ROOM-ID confirm</c>'.\n"),
+            None => (ctx.args, false)
+        };
+        let id = match id {
+            "this"|"here" => ctx.player.read().await.location.clone(),
+            other => other.to_string()
+        };
+
+        let res = plan(&*ctx.world.read().await, &id).await;
+        let plan = match res {
+            Ok(plan) => plan,
+            Err(e) => return tell_user!(ctx.writer, "<c red>Error:</c> {}.\n", e)
+        };
+        for area_id in plan.areas() {
+            validate_area_builder!(ctx, area_id);
+        }
+
+        if !confirmed {
+            return tell_user!(ctx.writer, "{}\nTo go ahead, use '<c yellow>rdelete {} confirm</c>'.\n", fmt_plan(&plan), plan.room_id);
+        }
+
+        if let Err(e) = execute(ctx.world, &plan).await {
+            log::error!("Deleting room '{}' failed: {:?}", plan.room_id, e);
+            return tell_user!(ctx.writer, "<c red>Error:</c> {}.\n", e);
+        }
+        log::info!("Room '{}' deleted by '{}'.", plan.room_id, ctx.player.read().await.id());
+        tell_user!(ctx.writer, "Room '{}' deleted.\n", plan.room_id);
+    }
+}
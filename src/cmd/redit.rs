@@ -1,11 +1,19 @@
 //! Room Editor - REdit.
+use std::collections::HashSet;
+
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use crate::{cmd::{Command, CommandCtx}, tell_user, traits::IdentityQuery, util::{clientstate::EditorMode, Editor}, validate_builder, world::room::Room, ClientState};
+use crate::{cmd::{Command, CommandCtx}, tell_user, traits::IdentityQuery, util::{clientstate::EditorMode, direction::Direction, history::EditHistory, Editor}, validate_area_builder, validate_builder, world::room::Room, ClientState};
 
 pub mod desc;
+pub mod door;
 pub mod exit;
+pub mod flags;
+pub mod hidden;
+pub mod jam;
+pub mod key;
 pub mod save;
+pub mod sector;
 pub mod title;
 
 pub struct ReditCommand;
@@ -14,6 +22,19 @@ pub struct ReditCommand;
 pub struct ReditState {
     pub entry: Room,
     pub dirty: bool,
+    #[serde(skip, default)]
+    pub history: EditHistory<Room>,
+    /// Exits whose other side is to follow suit on save.
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub sync_opposite: HashSet<Direction>,
+}
+
+impl ReditState {
+    /// Remember the entry as-is, about to be changed.
+    pub fn checkpoint(&mut self) {
+        self.history.record(&self.entry);
+        self.dirty = true;
+    }
 }
 
 #[async_trait]
@@ -46,19 +67,43 @@ impl Command for ReditCommand {
             other => other.to_string()
         };
 
+        // new rooms go into the area we're standing in.
+        let area_id = {
+            let w = ctx.world.read().await;
+            match w.rooms.get(&id).or_else(|| w.rooms.get(&g.location)) {
+                Some(r) => r.read().await.parent_id.clone(),
+                None => w.root.area.clone()
+            }
+        };
+        drop(g);
+        validate_area_builder!(ctx, area_id);
+        let mut g = ctx.player.write().await;
+
         if let Some(existing_entry) = ctx.world.read().await.rooms.get(&id) {
             log::info!("Player '{}' editing existing room '{}'", g.id(), id);
 
+            let mut entry = existing_entry.read().await.clone();
+            // pick up where the pending revision left off, if any.
+            if let Some(rev) = entry.revision.clone() {
+                rev.apply_to(&mut entry);
+                tell_user!(ctx.writer, "Continuing the unpublished revision by '{}'.\n", rev.draft.author);
+            }
             g.redit = Some(ReditState {
-                entry: existing_entry.read().await.clone(),
-                dirty: false
+                entry,
+                dirty: false,
+                history: EditHistory::default(),
+                sync_opposite: HashSet::new()
             });
         } else {
             log::info!("Player '{}' editing new room '{}'", g.id(), id);
 
+            let mut entry = Room::blank(Some(&id));
+            entry.parent_id = area_id;
             g.redit = Some(ReditState {
-                entry: Room::blank(Some(&id)),
-                dirty: true
+                entry,
+                dirty: true,
+                history: EditHistory::default(),
+                sync_opposite: HashSet::new()
             });
         }
 
@@ -68,7 +113,7 @@ impl Command for ReditCommand {
 
 impl Editor for ReditState {
     fn set_description(&mut self, desc: &str) {
-        self.dirty = true;
+        self.checkpoint();
         self.entry.set_description(desc);
     }
 }
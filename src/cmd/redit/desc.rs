@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use crate::{access_ed_entry, cmd::{Command, CommandCtx}, show_help, util::{ed::{edit_text, EdResult}, Editor}, validate_builder};
+use crate::{access_ed_entry, cmd::{Command, CommandCtx}, show_help, string::template, tell_user, util::{ed::{edit_text, EdResult}, Editor}, validate_builder};
 
 pub struct DescCommand;
 
@@ -12,6 +12,9 @@ impl Command for DescCommand {
         let verbose = match res {
             Ok(EdResult::ContentReady { text, verbose, .. }) => {
                 ctx.player.write().await.redit.set_description(&text);
+                if let Err(e) = template::check(&text) {
+                    tell_user!(ctx.writer, "<c red>Warning:</c> {}\n", e);
+                }
                 verbose
             },
             Ok(EdResult::NoChanges(true)) => true,
@@ -0,0 +1,72 @@
+//! Doors on [Room][crate::world::room::Room] exits.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, show_help, show_help_if_needed, tell_user, util::direction::Direction, validate_builder, world::exit::{jam::JamState, state::{DoorKind, ExitState, ExitStateQuery}}};
+
+pub struct DoorCommand;
+
+/// Door bits: kind, key, and jam.
+pub(crate) type DoorParts = (DoorKind, Option<String>, Option<JamState>);
+
+/// Split off a trailing `sync` from `args`.
+pub(crate) fn strip_sync(args: &str) -> (&str, bool) {
+    match args.trim().strip_suffix("sync") {
+        Some(rest) if rest.is_empty() || rest.ends_with(' ') => (rest.trim(), true),
+        _ => (args.trim(), false)
+    }
+}
+
+/// Reconfigure the door of exit towards `dir` with `change`.
+///
+/// With `sync`, the other side follows suit once the room gets saved.
+pub(crate) async fn reconfigure(ctx: &mut CommandCtx<'_>, dir: Direction, sync: bool, change: impl FnOnce(DoorParts) -> DoorParts + Send) {
+    let mut g = ctx.player.write().await;
+    let ed = g.redit.as_mut().unwrap();
+    let Some(exit) = ed.entry.exits.get(&dir) else {
+        drop(g);
+        return tell_user!(ctx.writer, "<c red>Error:</c> No exit towards '{}'.\n", dir);
+    };
+    let parts = (exit.state.door(), exit.state.key().map(String::from), exit.state.is_jammed());
+    let (kind, key, jam) = change(parts);
+    let state = match ExitState::configured(kind, key, jam) {
+        Ok(state) => state,
+        Err(e) => {
+            drop(g);
+            return tell_user!(ctx.writer, "<c red>Error:</c> {}.\n", e);
+        }
+    };
+    if state != exit.state {
+        ed.checkpoint();
+        if let Some(exit) = ed.entry.exits.get_mut(&dir) {
+            exit.state = state.clone();
+        }
+    }
+    if sync {
+        ed.sync_opposite.insert(dir.clone());
+    }
+    drop(g);
+    tell_user!(ctx.writer, "Exit '{}': <c cyan>{}</c>{}\n", dir, state.describe(),
+        if sync {" <c gray>(the other side follows on save)</c>"} else {""});
+}
+
+#[async_trait]
+impl Command for DoorCommand {
+    /// REdit 'door <dir> <none|open|closed|locked> [sync]'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+        show_help_if_needed!(ctx, "redit-door");
+
+        let (args, sync) = strip_sync(ctx.args);
+        let Some((dir, kind)) = args.split_once(' ') else { show_help!(ctx, "redit-door"); };
+        let Ok(kind) = DoorKind::try_from(kind.trim()) else {
+            let all = DoorKind::ALL.iter().map(|k| k.as_str()).collect::<Vec<_>>().join(", ");
+            return tell_user!(ctx.writer, "<c red>Error:</c> No such door as '{}'.\n<c gray>({})</c>\n", kind.trim(), all);
+        };
+        // no door, no key nor jam either.
+        reconfigure(ctx, Direction::from(dir), sync, |(_, key, jam)| match kind {
+            DoorKind::None => (kind, None, None),
+            DoorKind::Open => (kind, key, None),
+            _ => (kind, key, jam)
+        }).await;
+    }
+}
@@ -13,7 +13,7 @@ impl Command for ExitCommand {
         if ctx.args.is_empty() {
             let mut output = "<c yellow>-[ EXITS ]-</c>\n".to_string();
             for (dir, target) in &access_ed_entry!(ctx, redit).exits {
-                output.push_str(&format!("  <c cyan>{:<10}</c> → {}\n", dir, target));
+                output.push_str(&format!("  <c cyan>{:<10}</c> → {} <c gray>[{}]</c>\n", dir, target, target.state.describe()));
             }
             tell_user!(ctx.writer, output);
             return;
@@ -57,8 +57,8 @@ impl Command for ExitCommand {
         }
 
         if req_change != ed.entry.exits {
+            ed.checkpoint();
             ed.entry.exits = req_change;
-            ed.dirty = true;
             tell_user!(ctx.writer, "Exits updated.\n");
             drop(g);
             ExitCommand.exec({ctx.args = ""; ctx}).await;
@@ -0,0 +1,56 @@
+//! Room flag control.
+use async_trait::async_trait;
+
+use crate::{access_ed_entry, cmd::{Command, CommandCtx}, show_help_if_needed, tell_user, validate_builder, world::room::flags::RoomFlag};
+
+pub struct FlagsCommand;
+
+#[async_trait]
+impl Command for FlagsCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            let mut output = "<c yellow>-[ FLAGS ]-</c>\n".to_string();
+            let flags = access_ed_entry!(ctx, redit).flags.clone();
+            for flag in RoomFlag::ALL {
+                let mark = if flags.contains(&flag) {"<c green>+</c>"} else {"<c gray>-</c>"};
+                output.push_str(&format!("  {} {}\n", mark, flag));
+            }
+            tell_user!(ctx.writer, output);
+            return;
+        }
+        show_help_if_needed!(ctx, "redit-flags");
+
+        let mut g = ctx.player.write().await;
+        let ed = g.redit.as_mut().unwrap();
+        let mut req_change = ed.entry.flags.clone();
+        for part in ctx.args.split_whitespace() {
+            let (add, name) = match (part.strip_prefix('+'), part.strip_prefix('-')) {
+                (Some(name), _) => (true, name),
+                (_, Some(name)) => (false, name),
+                _ => (true, part)
+            };
+            let Ok(flag) = RoomFlag::try_from(name) else {
+                tell_user!(ctx.writer, "<c red>Error:</c> No such flag as '{}'.\n<c red>Edit aborted</c> due errorneous input.\n", name);
+                return;
+            };
+            if add {
+                req_change.insert(flag);
+            } else {
+                req_change.remove(&flag);
+            }
+        }
+
+        if req_change != ed.entry.flags {
+            ed.checkpoint();
+            ed.entry.flags = req_change;
+            tell_user!(ctx.writer, "Flags updated.\n");
+            drop(g);
+            FlagsCommand.exec({ctx.args = ""; ctx}).await;
+            return;
+        }
+
+        tell_user!(ctx.writer, "Flags stay as they were.\n");
+    }
+}
@@ -0,0 +1,61 @@
+//! Hidden/secret exit control.
+use async_trait::async_trait;
+
+use crate::{access_ed_entry, cmd::{Command, CommandCtx}, show_help, show_help_if_needed, tell_user, util::direction::Direction, validate_builder, world::exit::Concealment};
+
+pub struct HiddenCommand;
+
+#[async_trait]
+impl Command for HiddenCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            let mut output = "<c yellow>-[ HIDDEN EXITS ]-</c>\n".to_string();
+            for (dir, exit) in &access_ed_entry!(ctx, redit).exits {
+                let Some(c) = &exit.hidden else { continue };
+                let how = if c.secret {"secret".to_string()} else {format!("difficulty {}", c.difficulty)};
+                output.push_str(&format!("  <c cyan>{:<10}</c> → {} <c gray>({})</c>\n", dir, exit.destination, how));
+                if let Some(msg) = &c.message {
+                    output.push_str(&format!("    <c gray>\"{}\"</c>\n", msg));
+                }
+            }
+            tell_user!(ctx.writer, output);
+            return;
+        }
+        show_help_if_needed!(ctx, "redit-hidden");
+
+        let mut parts = ctx.args.splitn(3, ' ').map(|w| w.trim());
+        let (Some(dir), Some(how)) = (parts.next(), parts.next()) else { show_help!(ctx, "redit-hidden"); };
+        let message = parts.next().filter(|m| !m.is_empty()).map(|m| m.to_string());
+        let dir = Direction::from(dir);
+        let hidden = match how.to_lowercase().as_str() {
+            "off"|"none" => None,
+            "secret" => Some(Concealment { secret: true, message, ..Default::default() }),
+            n => match n.parse::<u8>() {
+                Ok(difficulty) if difficulty <= 100 => Some(Concealment { difficulty, message, ..Default::default() }),
+                _ => {
+                    tell_user!(ctx.writer, "<c red>Error:</c> Difficulty is a number between 0 and 100.\n");
+                    return;
+                }
+            }
+        };
+
+        {
+            let mut g = ctx.player.write().await;
+            let ed = g.redit.as_mut().unwrap();
+            let Some(exit) = ed.entry.exits.get(&dir) else {
+                tell_user!(ctx.writer, "<c red>Error:</c> No exit towards '{}'.\n", dir);
+                return;
+            };
+            if exit.hidden != hidden {
+                ed.checkpoint();
+                if let Some(exit) = ed.entry.exits.get_mut(&dir) {
+                    exit.hidden = hidden;
+                }
+            }
+        }
+
+        HiddenCommand.exec({ctx.args = ""; ctx}).await;
+    }
+}
@@ -0,0 +1,34 @@
+//! Preset jams on the doors of [Room][crate::world::room::Room] exits.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx, redit::door::{reconfigure, strip_sync}}, show_help, show_help_if_needed, tell_user, util::direction::Direction, validate_builder, world::exit::jam::{JamState, JammingMethod}};
+
+pub struct JamCommand;
+
+#[async_trait]
+impl Command for JamCommand {
+    /// REdit 'jam <dir> <none|lock METHOD|whole METHOD> [sync]'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+        show_help_if_needed!(ctx, "redit-jam");
+
+        let (args, sync) = strip_sync(ctx.args);
+        let args = args.split_whitespace().collect::<Vec<&str>>();
+        let jam = match (args.get(1), args.get(2)) {
+            (Some(&"none"), None) => None,
+            (Some(scope), Some(method)) => {
+                let Ok(method) = JammingMethod::try_from(*method) else {
+                    let all = JammingMethod::ALL.iter().map(|m| m.as_str()).collect::<Vec<_>>().join(", ");
+                    return tell_user!(ctx.writer, "<c red>Error:</c> No such jamming method as '{}'.\n<c gray>({})</c>\n", method, all);
+                };
+                match *scope {
+                    "lock" => Some(JamState::LockOnly(method)),
+                    "whole" => Some(JamState::WholeExit(method)),
+                    _ => { show_help!(ctx, "redit-jam"); }
+                }
+            },
+            _ => { show_help!(ctx, "redit-jam"); }
+        };
+        reconfigure(ctx, Direction::from(args[0]), sync, |(kind, key, _)| (kind, key, jam)).await;
+    }
+}
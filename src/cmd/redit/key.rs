@@ -0,0 +1,23 @@
+//! Keys to the doors on [Room][crate::world::room::Room] exits.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx, redit::door::{reconfigure, strip_sync}}, show_help, show_help_if_needed, util::direction::Direction, validate_builder};
+
+pub struct KeyCommand;
+
+#[async_trait]
+impl Command for KeyCommand {
+    /// REdit 'key <dir> <key-id|none> [sync]'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+        show_help_if_needed!(ctx, "redit-key");
+
+        let (args, sync) = strip_sync(ctx.args);
+        let Some((dir, key)) = args.split_once(' ') else { show_help!(ctx, "redit-key"); };
+        let key = match key.trim() {
+            "none"|"-" => None,
+            id => Some(id.to_string())
+        };
+        reconfigure(ctx, Direction::from(dir), sync, |(kind, _, jam)| (kind, key, jam)).await;
+    }
+}
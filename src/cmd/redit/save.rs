@@ -4,7 +4,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use tokio::sync::{RwLock, RwLockWriteGuard};
 
-use crate::{cmd::{Command, CommandCtx}, tell_user, traits::{IdentityQuery, save::DoesSave}, validate_builder, world::room::Room};
+use crate::{cmd::{Command, CommandCtx}, tell_user, traits::{IdentityQuery, save::DoesSave}, util::direction::Direction, validate_area_builder, validate_builder, world::{World, draft::{Draft, RoomRevision}, exit::Exit, room::Room}};
 
 pub struct SaveCommand;
 
@@ -12,20 +12,36 @@ pub struct SaveCommand;
 impl Command for SaveCommand {
     async fn exec(&self, ctx: &mut CommandCtx<'_>) {
         validate_builder!(ctx);
+        let area_id = ctx.player.read().await.redit.as_ref().map(|ed| ed.entry.parent_id.clone()).unwrap_or_default();
+        validate_area_builder!(ctx, area_id);
 
         let mut p = ctx.player.write().await;
+        let author = p.id().to_string();
+        let mut sync = vec![];
         if let Some(ref mut ed) = p.redit {
             let room: &mut Room = &mut ed.entry;
             ed.dirty = false;
-            // toss into the wild!
+            sync = ed.sync_opposite.drain()
+                .filter_map(|dir| room.exits.get(&dir).cloned().map(|e| (dir, e)))
+                .collect::<Vec<_>>();
+            // toss into the wild! …or rather, into the drafts.
             let mut w = ctx.world.write().await;
             let new: Arc<RwLock<Room>>;
             let mut new_lock: RwLockWriteGuard<'_, Room>;
+            let mut outcome = "Edits stored; the room stays a draft until published.\n";
             if let Err(e) = {( if let Some(orig) = w.rooms.get(room.id()) {
                 let mut lock = orig.write().await;
-                lock.shallow_copy(room);
+                if w.is_draft_room(&lock).await {
+                    // not live (yet), so no harm done.
+                    lock.shallow_copy(room);
+                } else {
+                    lock.revision = Some(RoomRevision::of(room, &author));
+                    room.revision = lock.revision.clone();
+                    outcome = "Edits stored as a revision; they go live once published.\n";
+                }
                 lock
             } else {
+                room.draft = Some(Draft::new(&author));
                 new = Arc::new(RwLock::new(room.clone()));
                 w.rooms.insert(room.id().into(), new.clone());
                 new_lock = new.write().await;
@@ -36,10 +52,69 @@ impl Command for SaveCommand {
                 return;
             }
 
-            tell_user!(ctx.writer, "Edits stored.\n");
+            tell_user!(ctx.writer, outcome);
         } else {
             log::debug!("Where'd the stored REdit state go for '{}'?!", p.id());
             tell_user!(ctx.writer, "You could've sworn you were editing a room, but…\n");
         }
+        let Some(room_id) = p.redit.as_ref().map(|ed| ed.entry.id.clone()) else { return };
+        drop(p);
+
+        // the other side of the doors, where asked to follow suit.
+        for (dir, exit) in sync {
+            let allowed = {
+                let w = ctx.world.read().await;
+                let area_id = match w.rooms.get(&exit.destination) {
+                    Some(r) => r.read().await.parent_id.clone(),
+                    None => continue
+                };
+                w.may_build(&*ctx.player.read().await, &area_id).await
+            };
+            if !allowed {
+                tell_user!(ctx.writer, "Not authorized to touch the other side in '{}'; left as-is.\n", exit.destination);
+                continue;
+            }
+            let msg = sync_other_side(&*ctx.world.read().await, &room_id, &dir, &exit, &author).await;
+            tell_user!(ctx.writer, msg);
+        }
+    }
+}
+
+/// Make the exit on the other side of `exit` — the one in the opposite
+/// direction, leading back to `room_id` — match its state, and save that room.
+///
+/// Live rooms get the change as a [RoomRevision], on top of any pending one.
+///
+/// # Returns
+/// What to tell the builder.
+async fn sync_other_side(w: &World, room_id: &str, dir: &Direction, exit: &Exit, author: &str) -> String {
+    let Ok(back) = dir.opposite() else {
+        return format!("No known opposite for '{}'; the other side left as-is.\n", dir);
+    };
+    let Some(other) = w.rooms.get(&exit.destination) else {
+        return format!("Room '{}' is gone; nothing to sync.\n", exit.destination);
+    };
+    let mut lock = other.write().await;
+    let mut revision = if w.is_draft_room(&lock).await { None } else {
+        Some(lock.revision.clone().unwrap_or_else(|| RoomRevision::of(&lock, author)))
+    };
+    let exits = match revision.as_mut() {
+        Some(rev) => &mut rev.exits,
+        None => &mut lock.exits
+    };
+    match exits.get_mut(&back) {
+        Some(e) if e.destination == room_id => e.state = exit.state.clone(),
+        _ => return format!("No exit {} from '{}' back here; the other side left as-is.\n", back, exit.destination)
+    }
+    let as_revision = revision.is_some();
+    if let Some(mut rev) = revision {
+        rev.draft = Draft::new(author);
+        lock.revision = Some(rev);
+    }
+    if let Err(e) = lock.save().await {
+        log::error!("Could not save '{}' after syncing its exit {}: {e:?}", exit.destination, back);
+        return format!("Something went awry with saving '{}'…\n", exit.destination);
     }
+    format!("The other side ({} of '{}') follows suit{}.\n", back, exit.destination,
+        if as_revision {", as a revision pending publish"} else {""})
 }
@@ -0,0 +1,35 @@
+//! Room sector type.
+use async_trait::async_trait;
+
+use crate::{access_ed_entry, cmd::{Command, CommandCtx}, show_help_if_needed, tell_user, validate_builder, world::room::flags::Sector};
+
+pub struct SectorCommand;
+
+#[async_trait]
+impl Command for SectorCommand {
+    /// REdit 'sector'.
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+
+        if ctx.args.is_empty() {
+            let current = access_ed_entry!(ctx, redit).sector;
+            let all = Sector::ALL.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", ");
+            return tell_user!(ctx.writer, "Sector: <c cyan>{}</c>\n<c gray>({})</c>\n", current, all);
+        }
+        show_help_if_needed!(ctx, "redit-sector");
+
+        let Ok(sector) = Sector::try_from(ctx.args.trim()) else {
+            return tell_user!(ctx.writer, "<c red>Error:</c> No such sector type as '{}'.\n", ctx.args.trim());
+        };
+        {
+            let mut g = ctx.player.write().await;
+            let ed = g.redit.as_mut().unwrap();
+            if ed.entry.sector != sector {
+                ed.checkpoint();
+                ed.entry.sector = sector;
+            }
+        }
+
+        SectorCommand.exec({ctx.args = ""; ctx}).await;
+    }
+}
@@ -19,7 +19,7 @@ impl Command for TitleCommand {
         {
             let mut g = ctx.player.write().await;
             let ed = g.redit.as_mut().unwrap();
-            ed.dirty = true;
+            ed.checkpoint();
             ed.entry.title = ctx.args.to_string();
         }
 
@@ -0,0 +1,30 @@
+//! 'redo' what was undone within some editor.
+use async_trait::async_trait;
+use crate::{cmd::{Command, CommandCtx, undo::{parse_steps, step_history}}, show_help, tell_user, tell_user_unk, util::clientstate::EditorMode, ClientState};
+
+pub struct RedoCommand;
+
+#[async_trait]
+impl Command for RedoCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        let ClientState::Editing { mode } = ctx.player.read().await.state() else {
+            // NOTE: 'redo' is a no-op when user is not within some editor context.
+            return tell_user_unk!(ctx.writer);
+        };
+        let Some(n) = parse_steps(ctx.args) else { show_help!(ctx, "redo"); };
+
+        let (done, (_, left)) = {
+            let mut p = ctx.player.write().await;
+            match mode {
+                EditorMode::Help => step_history!(p, hedit, redo, n),
+                EditorMode::Room => step_history!(p, redit, redo, n),
+                EditorMode::Area => step_history!(p, aedit, redo, n),
+                EditorMode::Item => step_history!(p, iedit, redo, n),
+            }
+        };
+        if done == 0 {
+            return tell_user!(ctx.writer, "Nothing to redo.\n");
+        }
+        tell_user!(ctx.writer, "Redid {} change{}; {} more to redo.\n", done, if done == 1 {""} else {"s"}, left);
+    }
+}
@@ -28,6 +28,8 @@ impl Command for ReturnCommand {
             ClientState::Editing { ref mode } => match mode {
                 EditorMode::Help => warn_or_close_editor!(ctx, hedit),
                 EditorMode::Room => warn_or_close_editor!(ctx, redit),
+                EditorMode::Area => warn_or_close_editor!(ctx, aedit),
+                EditorMode::Item => warn_or_close_editor!(ctx, iedit),
             }
             // …just to appease the analyzer… and to report an actual error while at it.
             _ => log::error!("'return' attempt from outside Playing|Editing context!")
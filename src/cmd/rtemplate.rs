@@ -0,0 +1,77 @@
+//! Named room templates — list, show, save, and delete.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, show_help, show_help_if_needed, string::slug::as_id, tell_user, traits::{IdentityQuery, save::DoesSave}, validate_builder, world::room::template::{ROOM_TEMPLATES, ROOM_TEMPLATE_PATH, RoomTemplate}};
+
+pub struct RtemplateCommand;
+
+#[async_trait]
+impl Command for RtemplateCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_builder!(ctx);
+        show_help_if_needed!(ctx, "rtemplate");
+
+        let args = ctx.args.split_whitespace().collect::<Vec<&str>>();
+        match (args[0].to_lowercase().as_str(), args.get(1)) {
+            ("list", _) => {
+                let reg = ROOM_TEMPLATES.read().await;
+                let list = reg.list();
+                if list.is_empty() {
+                    return tell_user!(ctx.writer, "No room templates (yet).\n");
+                }
+                let mut out = "<c yellow>-[ ROOM TEMPLATES ]-</c>\n".to_string();
+                for t in list {
+                    out.push_str(&format!("  <c cyan>{}</c> — {} <c gray>[{}]</c>\n", t.id(), t.title(), t.sector));
+                }
+                tell_user!(ctx.writer, "{}", out);
+            },
+
+            ("show", Some(id)) => {
+                let Some(t) = ROOM_TEMPLATES.read().await.get(id).cloned() else {
+                    return tell_user!(ctx.writer, "No room template called '{}'.\n", id);
+                };
+                let mut flags = t.flags.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+                flags.sort();
+                let mut exits = t.exits.iter().map(|(d, s)| format!("{} ({})", d, s)).collect::<Vec<_>>();
+                exits.sort();
+                tell_user!(ctx.writer, "<c yellow>-[ {} ]-</c>\n<c cyan>{}</c>\n{}\n<c gray>[sector: {}] [flags: {}] [exits: {}]</c>\n",
+                    t.id, t.title, t.description, t.sector,
+                    if flags.is_empty() {"none".into()} else {flags.join(", ")},
+                    if exits.is_empty() {"any".into()} else {exits.join(", ")});
+            },
+
+            ("save", Some(id)) => {
+                let Ok(id) = as_id(id) else {
+                    return tell_user!(ctx.writer, "<c red>Error:</c> '{}' won't do as a template ID.\n", id);
+                };
+                let source = match args.get(2) {
+                    None | Some(&"this") | Some(&"here") => ctx.player.read().await.location.clone(),
+                    Some(other) => other.to_string()
+                };
+                let room = ctx.world.read().await.rooms.get(&source).cloned();
+                let Some(room) = room else {
+                    return tell_user!(ctx.writer, "No room called '{}'.\n", source);
+                };
+                let mut t = RoomTemplate::from_room(&id, &*room.read().await);
+                if let Err(e) = t.save().await {
+                    log::error!("Saving room template '{}' failed: {e:?}", id);
+                    return tell_user!(ctx.writer, "<c red>Error saving the template!</c>\n");
+                }
+                ROOM_TEMPLATES.write().await.insert(t);
+                tell_user!(ctx.writer, "Room '{}' saved as template '<c cyan>{}</c>'.\n", source, id);
+            },
+
+            ("delete", Some(id)) => {
+                if ROOM_TEMPLATES.write().await.remove(id).is_none() {
+                    return tell_user!(ctx.writer, "No room template called '{}'.\n", id);
+                }
+                if let Err(e) = tokio::fs::remove_file(format!("{}/{}.json", *ROOM_TEMPLATE_PATH, id)).await {
+                    log::warn!("Removing room template '{}' file failed: {e:?}", id);
+                }
+                tell_user!(ctx.writer, "Room template '{}' deleted.\n", id);
+            },
+
+            _ => { show_help!(ctx, "rtemplate"); }
+        }
+    }
+}
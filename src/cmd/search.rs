@@ -0,0 +1,47 @@
+//! Search around for whatever's been tucked away.
+use async_trait::async_trait;
+use dicebag::DiceExt;
+
+use crate::{cmd::{Command, CommandCtx}, do_in_current_room, show_help, tell_user, world::exit::secret_id};
+
+pub struct SearchCommand;
+
+#[async_trait]
+impl Command for SearchCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        if ctx.args.starts_with('?') {
+            show_help!(ctx, "search");
+        }
+
+        // (secret ID, direction, difficulty, discovery message)
+        let mut candidates = vec![];
+        do_in_current_room!(ctx, |room| {
+            let r = room.read().await;
+            let p = ctx.player.read().await;
+            for (dir, exit) in &r.exits {
+                let Some(c) = exit.hidden.as_ref().filter(|c| !c.secret) else { continue };
+                let id = secret_id(&r.id, dir);
+                if !p.knows(&id) {
+                    candidates.push((id, dir.clone(), c.difficulty, c.message.clone()));
+                }
+            }
+        });
+
+        let mut found = String::new();
+        let mut p = ctx.player.write().await;
+        for (id, dir, difficulty, message) in candidates {
+            if 1.d100() <= difficulty as i32 {
+                continue;
+            }
+            p.learn(&id, "search");
+            found.push_str(&message.unwrap_or_else(|| format!("You discover a hidden way {}!", dir)));
+            found.push('\n');
+        }
+
+        if found.is_empty() {
+            tell_user!(ctx.writer, "You search around, but find nothing out of the ordinary.\n");
+        } else {
+            tell_user!(ctx.writer, "{}", found);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Secrets, lore, and whatnot one has learned along the way.
+//!
+//! Admins can also peek into, grant, and revoke what others know.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, player::knowledge::KnowledgeBase, show_help, tell_user, traits::IdentityQuery, util::timestamp::{fmt_span, unix_now}};
+
+pub struct SecretsCommand;
+
+#[async_trait]
+impl Command for SecretsCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        if ctx.args.starts_with('?') {
+            show_help!(ctx, "secrets");
+        }
+
+        let args = ctx.args.split_whitespace().collect::<Vec<&str>>();
+        if args.is_empty() {
+            let out = fmt_known(&ctx.player.read().await.known_secrets, "You haven't learned any secrets (yet).");
+            tell_user!(ctx.writer, "{}", out);
+            return;
+        }
+
+        let (admin, admin_id) = {
+            let p = ctx.player.read().await;
+            (p.access.is_admin(), p.id().to_string())
+        };
+        if !admin {
+            show_help!(ctx, "secrets");
+        }
+
+        let (Some(sub), Some(who)) = (args.first(), args.get(1)) else { show_help!(ctx, "secrets"); };
+        let Some(other) = ctx.world.read().await.find_player(who) else {
+            tell_user!(ctx.writer, "'{}' is not around.\n", who);
+            return;
+        };
+        match sub.to_lowercase().as_str() {
+            "list" => {
+                let out = fmt_known(&other.read().await.known_secrets, &format!("'{}' knows no secrets.", who));
+                tell_user!(ctx.writer, "{}", out);
+            },
+
+            "grant" => {
+                let Some(id) = args.get(2) else { show_help!(ctx, "secrets"); };
+                let source = if args.len() > 3 { args[3..].join(" ") } else { format!("granted by {}", admin_id) };
+                if other.write().await.learn(id, &source) {
+                    log::info!("'{}' granted '{}' knowledge of '{}'.", admin_id, who, id);
+                    tell_user!(ctx.writer, "'{}' now knows of '{}'.\n", who, id);
+                } else {
+                    tell_user!(ctx.writer, "'{}' already knew of '{}'.\n", who, id);
+                }
+            },
+
+            "revoke" => {
+                let Some(id) = args.get(2) else { show_help!(ctx, "secrets"); };
+                if other.write().await.forget(id) {
+                    log::info!("'{}' revoked '{}' knowledge of '{}'.", admin_id, who, id);
+                    tell_user!(ctx.writer, "'{}' no longer knows of '{}'.\n", who, id);
+                } else {
+                    tell_user!(ctx.writer, "'{}' didn't know of '{}' to begin with.\n", who, id);
+                }
+            },
+
+            _ => { show_help!(ctx, "secrets"); }
+        }
+    }
+}
+
+/// List what's `known`, or tell `none` if nothing.
+fn fmt_known(known: &KnowledgeBase, none: &str) -> String {
+    let list = known.list();
+    if list.is_empty() {
+        return format!("{}\n", none);
+    }
+    let now = unix_now();
+    let mut out = "<c yellow>-[ SECRETS ]-</c>\n".to_string();
+    for (id, k) in list {
+        let when = if k.learned_at == 0 {"a while".into()} else {fmt_span(now.saturating_sub(k.learned_at))};
+        out.push_str(&format!("  <c cyan>{}</c> <c gray>— {} ago, via {}</c>\n", id, when, k.source));
+    }
+    out
+}
@@ -0,0 +1,146 @@
+//! Data directory snapshots — list, take, and restore bits from them.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::{cmd::{Command, CommandCtx}, player::{Player, pc::SAVE_PATH}, show_help, show_help_if_needed, string::slug::Sluggable, tell_user, traits::{IdentityQuery, save::DoesSave}, util::{snapshot::{SnapshotInfo, create_snapshot, find_snapshot, list_snapshots, prune_snapshots, read_entry}, timestamp::{fmt_span, unix_now}}, validate_admin, world::room::Room};
+
+pub struct SnapshotCommand;
+
+#[async_trait]
+impl Command for SnapshotCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_admin!(ctx);
+        show_help_if_needed!(ctx, "snapshot");
+
+        let args = ctx.args.split_whitespace().collect::<Vec<&str>>();
+        match args[0].to_lowercase().as_str() {
+            "list" => {
+                let list = list_snapshots();
+                if list.is_empty() {
+                    tell_user!(ctx.writer, "No snapshots (yet).\n");
+                    return;
+                }
+                let now = unix_now();
+                let mut out = String::new();
+                for s in list {
+                    out.push_str(&format!("  <c yellow>{}</c> — {} ago, {} KiB\n", s.name, fmt_span(now.saturating_sub(s.taken_at)), s.size / 1024));
+                }
+                tell_user!(ctx.writer, "{}", out);
+            },
+
+            "now" => {
+                tell_user!(ctx.writer, "Taking a snapshot…\n");
+                match tokio::task::spawn_blocking(|| { let r = create_snapshot(); prune_snapshots(); r }).await {
+                    Ok(Ok(name)) => {
+                        log::info!("Snapshot '{}' taken on demand.", name);
+                        tell_user!(ctx.writer, "Snapshot '{}' done.\n", name);
+                    },
+                    Ok(Err(e)) => {
+                        log::error!("Snapshot failed: {e:?}");
+                        tell_user!(ctx.writer, "<c red>Snapshot failed</c>: {}\n", e);
+                    },
+                    Err(e) => log::error!("Snapshot task went poof: {e:?}"),
+                }
+            },
+
+            "restore" => {
+                if args.len() < 4 {
+                    show_help!(ctx, "snapshot");
+                }
+                let Some(snap) = find_snapshot(args[1]) else {
+                    tell_user!(ctx.writer, "No (single) snapshot matching '{}'.\n", args[1]);
+                    return;
+                };
+                match args[2].to_lowercase().as_str() {
+                    "save" => restore_save(ctx, &snap, args[3]).await,
+                    "room" => restore_room(ctx, &snap, args[3]).await,
+                    _ => { show_help!(ctx, "snapshot"); }
+                }
+            },
+
+            _ => { show_help!(ctx, "snapshot"); }
+        }
+    }
+}
+
+/// Restore a player save — only while the player is offline, so that the live
+/// [Player] won't just overwrite it again at the next autosave.
+async fn restore_save(ctx: &mut CommandCtx<'_>, snap: &SnapshotInfo, who: &str) {
+    let slug = who.slugify();
+    if ctx.world.read().await.find_player(&slug).is_some() {
+        tell_user!(ctx.writer, "'{}' is online. Restoring a save works only while they're not.\n", who);
+        return;
+    }
+    let content = match tokio::task::spawn_blocking({
+        let snap = snap.clone();
+        let entry = format!("save/{}.save", slug);
+        move || read_entry(&snap, &entry)
+    }).await {
+        Ok(Ok(c)) => c,
+        Ok(Err(e)) => return tell_user!(ctx.writer, "{}\n", e),
+        Err(e) => return log::error!("Snapshot task went poof: {e:?}"),
+    };
+    if let Err(e) = serde_json::from_str::<Player>(&content) {
+        log::error!("Save '{}' in '{}' is malformed: {e:?}", slug, snap.name);
+        tell_user!(ctx.writer, "<c red>The save in that snapshot is malformed.</c>\n");
+        return;
+    }
+    if let Err(e) = tokio::fs::write(format!("{}/{}.save", *SAVE_PATH, slug), content).await {
+        log::error!("Could not write restored save '{}': {e:?}", slug);
+        tell_user!(ctx.writer, "Something went awry…\n");
+        return;
+    }
+    log::info!("Save '{}' restored from '{}'.", slug, snap.name);
+    tell_user!(ctx.writer, "Save '{}' restored from '{}'.\n", slug, snap.name);
+}
+
+/// Restore a room's definition. Live rooms are restored in place (title,
+/// description, exits) so players within and references to it stay intact.
+async fn restore_room(ctx: &mut CommandCtx<'_>, snap: &SnapshotInfo, id: &str) {
+    let content = match tokio::task::spawn_blocking({
+        let snap = snap.clone();
+        let entry = format!("rooms/{}.room", id);
+        move || read_entry(&snap, &entry)
+    }).await {
+        Ok(Ok(c)) => c,
+        Ok(Err(e)) => return tell_user!(ctx.writer, "{}\n", e),
+        Err(e) => return log::error!("Snapshot task went poof: {e:?}"),
+    };
+    let restored: Room = match serde_json::from_str(&content) {
+        Ok(r) => r,
+        Err(e) => {
+            log::error!("Room '{}' in '{}' is malformed: {e:?}", id, snap.name);
+            tell_user!(ctx.writer, "<c red>The room in that snapshot is malformed.</c>\n");
+            return;
+        }
+    };
+
+    let mut w = ctx.world.write().await;
+    let room = match w.rooms.get(id) {
+        Some(r) => {
+            r.write().await.shallow_copy(&restored);
+            r.clone()
+        },
+        None => {
+            let r = Arc::new(RwLock::new(restored));
+            w.rooms.insert(id.into(), r.clone());
+            {
+                let mut lock = r.write().await;
+                if let Some(area) = w.areas.get(&lock.parent_id) {
+                    area.write().await.rooms.insert(lock.id().into(), Arc::downgrade(&r));
+                    lock.parent = Arc::downgrade(area);
+                }
+            }
+            r
+        }
+    };
+    if let Err(e) = room.write().await.save().await {
+        log::error!("Could not save restored room '{}': {e:?}", id);
+        tell_user!(ctx.writer, "Something went awry…\n");
+        return;
+    }
+    log::info!("Room '{}' restored from '{}'.", id, snap.name);
+    tell_user!(ctx.writer, "Room '{}' restored from '{}'.\n", id, snap.name);
+}
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use crate::{cmd::{Command, CommandCtx}, do_in_current_room, item::{inventory::Storage, ItemError}, show_help_if_needed, tell_user, traits::{IdentityQuery, Owned}};
+use crate::{cmd::{Command, CommandCtx}, do_in_current_room, item::{inventory::Storage, ItemError}, player::journal::JournalEntry, show_help_if_needed, tell_user, traits::{IdentityQuery, Owned}};
 
 pub struct TakeCommand;
 
@@ -21,7 +21,10 @@ impl Command for TakeCommand {
                         log::debug!("Item '{}' is now owned by '{}': EO {err_o:?} / EOO {err_oo:?}", id, p_id);
                     }
 
-                    if let Err(item) = ctx.player.write().await.inventory.try_insert(item) {
+                    let gained = JournalEntry::Gained(item.clone());
+                    let mut p = ctx.player.write().await;
+                    if let Err(item) = p.inventory.try_insert(item) {
+                        drop(p);
                         // tell the user why exactly taking the item didn't work...
                         match &item {
                             ItemError::NoSpace(_) => {tell_user!(ctx.writer, "Uh oh - can't carry that much. Make some space in your inventory first…");},
@@ -34,6 +37,8 @@ impl Command for TakeCommand {
                             ctx.world.write().await.lost_and_found.insert(e.id().to_string(), e);
                         }
                     } else {
+                        p.journal(gained);
+                        drop(p);
                         log::debug!("Item '{}' taken from room.", id);
                         tell_user!(ctx.writer, "You nabbed {}.\n", id);
                     }
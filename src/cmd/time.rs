@@ -0,0 +1,19 @@
+//! What time is it?
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, show_help, tell_user};
+
+pub struct TimeCommand;
+
+#[async_trait]
+impl Command for TimeCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        if ctx.args.starts_with('?') {
+            show_help!(ctx, "time");
+        }
+
+        let now = ctx.world.read().await.time();
+        tell_user!(ctx.writer, "It is <c yellow>{}</c>, {}.\n", now,
+            if now.is_daytime() {"in broad daylight"} else {"in the dark of night"});
+    }
+}
@@ -5,7 +5,7 @@ use std::{fmt::Display, sync::Arc};
 
 use async_trait::async_trait;
 use tokio::sync::RwLock;
-use crate::{cmd::{look::LookCommand, Command, CommandCtx}, player::Player, show_help, tell_user, traits::IdentityQuery, validate_admin, world::{room::Room, SharedWorld}};
+use crate::{cmd::{look::LookCommand, Command, CommandCtx}, player::{Player, journal::JournalEntry}, show_help, tell_user, traits::IdentityQuery, validate_admin, world::{area::AreaMessage, room::{Room, flags::RoomFlag}, SharedWorld}};
 
 pub struct TranslocateCommand;
 
@@ -43,30 +43,46 @@ impl Command for TranslocateCommand {
             (args[0], args[1])
         };
 
-        if ctx.world.read().await.rooms.get(where_to).is_none() {
+        if !ctx.world.read().await.rooms.contains_key(where_to) {
             tell_user!(ctx.writer, "No such room exists.\n");
             return;
         }
 
         // Who's being translocated?
-        match who {
-            "self" => {
-                let source = ctx.player.read().await.location.clone();
-                let _ = translocate(ctx.world, Some(source), where_to.into(), ctx.player.clone()).await;
-                let look = LookCommand;
-                look.exec({ctx.args = ""; ctx}).await;
-            },
-            _ => {
-                let other = ctx.world.read().await.find_player(who);
-                if let Some(found) = other {
-                    log::info!("Translocating other player, '{}'", found.read().await.id());
-                    let source = found.read().await.location.clone();
-                    let _ = translocate(ctx.world, Some(source), where_to.into(), found.clone()).await;
-                } else {
-                    tell_user!(ctx.writer, "Could not locate '{}'", who);
+        let traveller = match who {
+            "self" => ctx.player.clone(),
+            _ => match ctx.world.read().await.find_player(who) {
+                Some(found) => found,
+                None => return tell_user!(ctx.writer, "Could not locate '{}'", who)
+            }
+        };
+        let source = traveller.read().await.location.clone();
+        // NoTeleport wards both ways — no getting in, nor out, unless an admin.
+        if !traveller.read().await.access.is_admin() {
+            for room_id in [source.as_str(), where_to] {
+                if is_warded(ctx.world, room_id).await {
+                    log::info!("Translocation of '{}' blocked by the ward of '{}'.", traveller.read().await.id(), room_id);
+                    return tell_user!(ctx.writer, "'{}' is warded against translocation — only admins get in (or out) that way.\n", room_id);
                 }
             }
         }
+
+        if who == "self" {
+            let _ = translocate(ctx.world, Some(source), where_to.into(), traveller).await;
+            let look = LookCommand;
+            look.exec({ctx.args = ""; ctx}).await;
+        } else {
+            log::info!("Translocating other player, '{}'", traveller.read().await.id());
+            let _ = translocate(ctx.world, Some(source), where_to.into(), traveller).await;
+        }
+    }
+}
+
+/// Is room `room_id` flagged [NoTeleport][RoomFlag::NoTeleport]?
+async fn is_warded(world: &SharedWorld, room_id: &str) -> bool {
+    match world.read().await.rooms.get(room_id) {
+        Some(r) => r.read().await.has_flag(RoomFlag::NoTeleport),
+        None => false
     }
 }
 
@@ -113,8 +129,12 @@ pub(crate) async fn translocate(
                 p.room = Arc::<RwLock<Room>>::downgrade(&r);
                 if !is_same && p.location != r_id {
                     p.location = r_id.clone();
+                    p.journal(JournalEntry::Moved(r_id.clone()));
                     p.inc_act_count();// treat as 'activity' for auto-saving purposes.
                 }
+                let who = p.id().to_string();
+                drop(p);
+                greet_arrival(r, &who).await;
             }
         } else {
             // TODO: add fuzzy search logic!
@@ -145,3 +165,23 @@ pub(crate) async fn translocate(
 
     Ok(ok_err)
 }
+
+/// Let the [Mob][crate::mob::Mob]s present react to `who` having arrived.
+///
+/// Nobody gets to be hostile in a [safe][RoomFlag::Safe] room, though.
+async fn greet_arrival(room: &Arc<RwLock<Room>>, who: &str) {
+    let (reactions, area) = {
+        let r = room.read().await;
+        if r.has_flag(RoomFlag::Safe) {
+            return;
+        }
+        let reactions = r.mobs.values()
+            .filter_map(|m| m.react_to_arrival(who))
+            .map(|msg| AreaMessage::room(&r.id, msg))
+            .collect::<Vec<_>>();
+        (reactions, r.parent.upgrade())
+    };
+    if let (false, Some(area)) = (reactions.is_empty(), area) {
+        area.write().await.outbox.extend(reactions);
+    }
+}
@@ -0,0 +1,54 @@
+//! 'undo' the latest edit(s) within some editor.
+use async_trait::async_trait;
+use crate::{cmd::{Command, CommandCtx}, show_help, tell_user, tell_user_unk, util::clientstate::EditorMode, ClientState};
+
+pub struct UndoCommand;
+
+/// Step the editor's entry back (or forth) `$n` times, via its history.
+macro_rules! step_history {
+    ($p:expr, $ed:ident, $op:ident, $n:expr) => {{
+        let ed = $p.$ed.as_mut().unwrap();
+        let mut done = 0;
+        while done < $n && ed.history.$op(&mut ed.entry) {
+            done += 1;
+        }
+        if done > 0 {
+            ed.dirty = true;
+        }
+        (done, ed.history.steps())
+    }};
+}
+pub(crate) use step_history;
+
+/// Parse an optional step count.
+pub(crate) fn parse_steps(args: &str) -> Option<usize> {
+    match args.trim() {
+        "" => Some(1),
+        n => n.parse::<usize>().ok().filter(|n| *n > 0)
+    }
+}
+
+#[async_trait]
+impl Command for UndoCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        let ClientState::Editing { mode } = ctx.player.read().await.state() else {
+            // NOTE: 'undo' is a no-op when user is not within some editor context.
+            return tell_user_unk!(ctx.writer);
+        };
+        let Some(n) = parse_steps(ctx.args) else { show_help!(ctx, "undo"); };
+
+        let (done, (left, _)) = {
+            let mut p = ctx.player.write().await;
+            match mode {
+                EditorMode::Help => step_history!(p, hedit, undo, n),
+                EditorMode::Room => step_history!(p, redit, undo, n),
+                EditorMode::Area => step_history!(p, aedit, undo, n),
+                EditorMode::Item => step_history!(p, iedit, undo, n),
+            }
+        };
+        if done == 0 {
+            return tell_user!(ctx.writer, "Nothing to undo.\n");
+        }
+        tell_user!(ctx.writer, "Undid {} change{}; {} more to undo.\n", done, if done == 1 {""} else {"s"}, left);
+    }
+}
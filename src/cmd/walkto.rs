@@ -0,0 +1,82 @@
+//! Walk somewhere, step by step, the shortest way there is.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx, goto::GotoCommand, path::fmt_path}, show_help_if_needed, tell_user, validate_admin, world::path::{PathOptions, find_path}};
+
+pub struct WalktoCommand;
+
+#[async_trait]
+impl Command for WalktoCommand {
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_admin!(ctx);
+        show_help_if_needed!(ctx, "walkto");
+
+        let target = ctx.args.trim().to_string();
+        let from = ctx.player.read().await.location.clone();
+        // 'goto' doesn't pass through closed doors, so neither do we.
+        let dirs = match find_path(&*ctx.world.read().await, &from, &target, &PathOptions { through_hidden: true, through_drafts: true, ..Default::default() }).await {
+            Ok(dirs) => dirs,
+            Err(e) => return tell_user!(ctx.writer, "{}.\n", e),
+        };
+        if dirs.is_empty() {
+            return tell_user!(ctx.writer, "You're already there.\n");
+        }
+        tell_user!(ctx.writer, "Walking to '{}': {}\n", target, fmt_path(&dirs));
+
+        for dir in dirs {
+            let before = ctx.player.read().await.location.clone();
+            let step = dir.as_key().to_string();
+            let mut step_ctx = CommandCtx {
+                player: ctx.player.clone(),
+                state: ctx.state.clone(),
+                world: ctx.world,
+                tx: ctx.tx,
+                args: &step,
+                writer: ctx.writer,
+            };
+            GotoCommand.exec(&mut step_ctx).await;
+            if ctx.player.read().await.location == before {
+                tell_user!(ctx.writer, "Stopped short at '{}'.\n", before);
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod cmd_walkto_tests {
+    use std::sync::Arc;
+    use tokio::{io::{AsyncBufReadExt, AsyncReadExt, BufReader, AsyncWriteExt}, net::{TcpListener, TcpStream}, sync::{broadcast, RwLock}};
+    use crate::{async_client_for_tests, async_server_for_tests, player::{Access, Player}, player_and_listener_for_tests, string::ansi::AntiAnsi, util::{Broadcast, ClientState, direction::{Direction, custom::{CUSTOM_DIRECTIONS, CustomDirections}}}, world::{World, area::Area, exit::{state::ExitState, *}, room::Room}, world_for_tests};
+    use super::*;
+
+    #[tokio::test]
+    async fn walk_through_displayed_custom_exit() {
+        let _ = env_logger::try_init();
+        let _ = CUSTOM_DIRECTIONS.set(CustomDirections::from_json(r#"{
+            "portal": { "opposite": "portal", "display": "shimmering portal" }
+        }"#).unwrap());
+
+        // swap void's east exit for a portal…
+        let w = world_for_tests!();
+        {
+            let lock = w.read().await;
+            let mut void = lock.rooms.get("void").unwrap().write().await;
+            void.exits.remove(&Direction::East);
+            void.exits.insert(Direction::Custom("portal".into()), Exit { destination: "clearing".into(), state: ExitState::Open { key_id: None }, hidden: None });
+        }
+
+        let (p, listener, addr, tx) = player_and_listener_for_tests!();
+        p.write().await.set_access(Access::Admin);
+        let client_task = async_client_for_tests!(addr, "walkto clearing");
+        let server_task = async_server_for_tests!(w, listener, tx, addr, p, 1);
+
+        let (_, client_out) = tokio::join!(server_task, client_task);
+        let output_string = client_out.unwrap();
+        let output_string = output_string.strip_ansi();
+
+        assert!(output_string.contains("shimmering portal"));
+        assert!(output_string.contains("Omega"));
+        assert!(!output_string.contains("Stopped short"));
+    }
+}
@@ -0,0 +1,44 @@
+//! World integrity check, on demand.
+use async_trait::async_trait;
+
+use crate::{cmd::{Command, CommandCtx}, show_help, tell_user, validate_admin, world::integrity::{self, IssueKind}};
+
+pub struct WcheckCommand;
+
+#[async_trait]
+impl Command for WcheckCommand {
+    /// `wcheck [kind…] [area-or-room…]`
+    async fn exec(&self, ctx: &mut CommandCtx<'_>) {
+        validate_admin!(ctx);
+        if ctx.args.starts_with('?') {
+            show_help!(ctx, "wcheck");
+        }
+
+        // split args into issue kinds and area/room filters.
+        let (mut kinds, mut places) = (vec![], vec![]);
+        for arg in ctx.args.split_whitespace().map(|a| a.to_lowercase()) {
+            match IssueKind::ALL.into_iter().find(|k| k.as_str() == arg) {
+                Some(k) => kinds.push(k),
+                None => places.push(arg),
+            }
+        }
+
+        let issues = integrity::check(&*ctx.world.read().await).await;
+        let total = issues.len();
+        let shown = issues.into_iter()
+            .filter(|i| kinds.is_empty() || kinds.contains(&i.kind))
+            .filter(|i| places.is_empty() || places.iter().any(|p| *p == i.area_id || i.room_id.contains(p.as_str())))
+            .collect::<Vec<_>>();
+
+        if total == 0 {
+            tell_user!(ctx.writer, "<c green>All good</c> — no issues found.\n");
+            return;
+        }
+        let mut out = String::new();
+        for i in &shown {
+            out.push_str(&format!("  <c red>{:<11}</c> <c cyan>{}</c>:{} — {}\n", i.kind, i.area_id, i.room_id, i.detail));
+        }
+        out.push_str(&format!("<c yellow>{}</c> of {} issue{} shown.\n", shown.len(), total, if total == 1 {""} else {"s"}));
+        tell_user!(ctx.writer, "{}", out);
+    }
+}
@@ -1,17 +1,20 @@
 use std::time::Duration;
-use tokio::time::{sleep_until, Instant};
-use crate::{traits::tickable::Tickable, world::SharedWorld};
+use tokio::{sync::broadcast, time::{sleep_until, Instant}};
+use crate::{traits::tickable::Tickable, util::Broadcast, world::SharedWorld};
 
 const MILLIS_PER_TICK: u64 = 100; // ~10 ticks/sec (with 100ms each).
+pub(crate) const TICKS_PER_SECOND: u64 = 1000 / MILLIS_PER_TICK;
 
 /// The heart of the machinery — game loop.
 /// 
 /// # Arguments
 /// - `world`— shared world, shared pain ;-)
-pub async fn game_loop(world: SharedWorld) {
+/// - `tx`— broadcast channel sender, for whatever the world has to say.
+pub async fn game_loop(world: SharedWorld, tx: broadcast::Sender<Broadcast>) {
     let duration = Duration::from_millis(MILLIS_PER_TICK);
     let mut next_tick = Instant::now() + duration;
-    let mut uptime = 0;
+    // the world keeps on aging from where it left off.
+    let mut uptime = world.read().await.uptime();
 
     log::info!("game_loop firing up …");
     loop {
@@ -21,7 +24,14 @@ pub async fn game_loop(world: SharedWorld) {
         #[cfg(test)]{log::debug!("game_loop - Tick {}", uptime);}
 
         // Tick-tock goes the clock and the world spins 'round and 'round…
-        world.write().await.tick(uptime).await;
+        let messages = {
+            let mut w = world.write().await;
+            w.tick(uptime).await;
+            w.take_area_messages().await
+        };
+        for (area_id, m) in messages {
+            let _ = tx.send(Broadcast::Area { area_id, room_id: m.room_id, message: m.message });
+        }
 
         if Instant::now() > next_tick {
             log::warn!("Clock skew! Busy day! Lagging behind!");
@@ -1,7 +1,7 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{sync::Arc, time::Duration};
 use futures::{stream, StreamExt};
 use tokio::{sync::RwLock, time::{self}};
-use crate::{item::ItemError, player::Player, string::WordSet, traits::{save::DoesSave, IdentityQuery}, util::badname::{load_bad_names, BAD_NAMES_FILEPATH}, world::SharedWorld, AUTOSAVE_QUEUE_INTERVAL};
+use crate::{item::{vault::{Vault, VAULT}, ItemError}, player::Player, string::WordSet, traits::{save::DoesSave, IdentityQuery}, util::{badname::{load_bad_names, BAD_NAMES_FILEPATH}, snapshot::{create_snapshot, prune_snapshots}, timestamp::unix_now}, world::SharedWorld, AUTOSAVE_QUEUE_INTERVAL};
 
 const LOGOUT_QUEUE_INTERVAL: u64 = 1; // once per second, about.
 pub(crate) const DEFAULT_AUTOSAVE_QUEUE_INTERVAL: u64 = 300; // once per 5 minutes, about.
@@ -10,6 +10,10 @@ pub(crate) const DEFAULT_AUTOSAVE_ACT_COUNT_THRESHOLD: usize = 16;
 const LOST_AND_FOUND_QUEUE_INTERVAL: u64 = 900; // once per 15 minutes, about.
 #[cfg(feature = "localtest")]
 const LOST_AND_FOUND_QUEUE_INTERVAL: u64 = 5; // once per 5 sec, about.
+#[cfg(not(feature = "localtest"))]
+const SNAPSHOT_INTERVAL: u64 = 3600; // once per hour, about.
+#[cfg(feature = "localtest")]
+const SNAPSHOT_INTERVAL: u64 = 60; // once per minute, about.
 
 /// One heart of the machinery — disk I/O loop.
 /// 
@@ -23,7 +27,7 @@ pub async fn io_loop(
     let mut autosave_interval = time::interval(Duration::from_secs(*AUTOSAVE_QUEUE_INTERVAL.read().await));
     let mut config_change_interval = time::interval(Duration::from_millis(100));
     let mut lost_and_found_interval = time::interval(Duration::from_secs(LOST_AND_FOUND_QUEUE_INTERVAL));
-    let mut lost_and_found: HashMap<String, ItemError> = HashMap::new();
+    let mut snapshot_interval = time::interval(Duration::from_secs(SNAPSHOT_INTERVAL));
 
     log::info!("io_loop firing up … {} second{} logout queue, {} second{} auto-save queue.",
             LOGOUT_QUEUE_INTERVAL, if LOGOUT_QUEUE_INTERVAL==1 {""} else {"s"},
@@ -37,6 +41,11 @@ pub async fn io_loop(
         log::info!("Bad words ready → releasing lock for public use.");
     }
 
+    {
+        log::info!("Opening lost-and-found vault...");
+        *VAULT.write().await = Vault::load().await;
+    }
+
     loop {
         tokio::select! {
             _ = config_change_interval.tick() => {
@@ -101,12 +110,35 @@ pub async fn io_loop(
                     w.lost_and_found.drain().collect::<Vec<(String, ItemError)>>()
                 };
                 let num = new_losts.len();
-                for (id, err) in new_losts {
-                    lost_and_found.insert(id, err);
+                let now = unix_now();
+                let mut vault = VAULT.write().await;
+                for (_, err) in new_losts {
+                    vault.stash(err, now);
+                }
+                let expired = vault.prune(now);
+                if !expired.is_empty() {
+                    log::info!("{} item{} expired from the lost-and-found vault: {}", expired.len(), if expired.len() != 1 {"s"} else {""}, expired.join(", "));
                 }
                 if num > 0 {
                     log::info!("Collected {} item{} into safety from The Void.", num, if num != 1 {"s"} else {""});
-                    log::info!("Currently holding onto {} item{} in total.", lost_and_found.len(), if lost_and_found.len() != 1 {"s"} else {""});
+                    log::info!("Currently holding onto {} item{} in total.", vault.len(), if vault.len() != 1 {"s"} else {""});
+                }
+                if (num > 0 || !expired.is_empty()) && let Err(e) = vault.save().await {
+                    log::error!("Failed to save lost-and-found vault: {:?}", e);
+                }
+            },
+
+            _ = snapshot_interval.tick() => {
+                log::trace!("Snapshot cycle initiated … @{}s intervals.", snapshot_interval.period().as_secs());
+                match tokio::task::spawn_blocking(|| (create_snapshot(), prune_snapshots())).await {
+                    Ok((Ok(name), pruned)) => {
+                        log::info!("Snapshot '{}' taken.", name);
+                        if pruned > 0 {
+                            log::info!("Pruned {} old snapshot{}.", pruned, if pruned != 1 {"s"} else {""});
+                        }
+                    },
+                    Ok((Err(e), _)) => log::error!("Snapshot failed: {e:?}"),
+                    Err(e) => log::error!("Snapshot task went poof: {e:?}"),
                 }
             },
         }
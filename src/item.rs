@@ -8,3 +8,4 @@ pub use item::{Item, ItemError, ItemMap};
 pub mod weapon;
 pub mod key;
 pub mod tool;
+pub mod vault;
@@ -1,13 +1,18 @@
 //! # Item Blueprints.
-//! 
+//!
 //! Items generally are based on blueprints when created.
 //!
 //! These blueprints may be custom in the core, but they all
-//! fall into a few general categories.
+//! fall into a few general categories. Custom ones live under
+//! `data/blueprints`, one `<id>.json` file each.
+
+use std::{collections::HashMap, fmt::Display, sync::Arc};
 
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
 
-use crate::traits::IdentityQuery;
+use crate::{DATA_PATH, item::{Item, inventory::{Container, Content, StorageCapacity}, key::Key, tool::Tool, weapon::Weapon}, player::Player, traits::{Description, IdentityQuery, save::{DoesSave, SaveError}}};
 
 /// A trait for anything and everything with a "blueprint" of sorts.
 pub trait BlueprintID : IdentityQuery {
@@ -22,10 +27,74 @@ pub(crate) const BACKPACK_BP_ID: &'static str = "backpack";
 pub(crate) const RANGED_BP_ID: &'static str = "weapon-ranged";
 pub(crate) const MELEE_BP_ID: &'static str = "weapon-melee";
 
+pub(crate) static BLUEPRINT_PATH: Lazy<String> = Lazy::new(|| format!("{}/blueprints", *DATA_PATH));
+pub(crate) static BLUEPRINTS: Lazy<Arc<RwLock<BlueprintRegistry>>> = Lazy::new(|| Arc::new(RwLock::new(BlueprintRegistry::default())));
+/// Largest capacity a container blueprint may have.
+pub(crate) const MAX_BP_CAPACITY: usize = 64;
+
+fn capacity_default() -> usize { 32 }
+
+/// The general category an [Item] made off of a [Blueprint] falls into.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum BlueprintKind {
+    Container,
+    #[default]
+    Melee,
+    Ranged,
+    Tool,
+    Key,
+}
+
+impl BlueprintKind {
+    pub const ALL: [BlueprintKind; 5] = [Self::Container, Self::Melee, Self::Ranged, Self::Tool, Self::Key];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Container => "container",
+            Self::Melee => "melee",
+            Self::Ranged => "ranged",
+            Self::Tool => "tool",
+            Self::Key => "key",
+        }
+    }
+}
+
+impl TryFrom<&str> for BlueprintKind {
+    type Error = ();
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::ALL.into_iter().find(|k| k.as_str() == value.to_lowercase()).ok_or(())
+    }
+}
+
+impl Display for BlueprintKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Blueprint {
-    id: String,
-    title: String,
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub kind: BlueprintKind,
+    /// Containers only.
+    #[serde(default = "capacity_default")]
+    pub capacity: usize,
+    /// Keys only; the key breaks after a single use.
+    #[serde(default)]
+    pub one_time: bool,
+    /// Tools only; the tool is spent after a single use.
+    #[serde(default)]
+    pub single_use: bool,
+    /// Tools only; does it shed light?
+    #[serde(default)]
+    pub light: bool,
+    /// Who made the blueprint; only they (and admins) get to change it.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub author: String,
 }
 
 impl BlueprintID for Blueprint {
@@ -36,3 +105,204 @@ impl IdentityQuery for Blueprint {
     fn id<'a>(&'a self) -> &'a str { self.bp_id() }
     fn title<'a>(&'a self) -> &'a str { &self.title }
 }
+
+impl Description for Blueprint {
+    fn description(&self) -> &str { &self.description }
+}
+
+impl Blueprint {
+    /// A fresh blueprint `id`, of [kind][BlueprintKind] [melee][BlueprintKind::Melee] until told otherwise.
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.into(),
+            title: id.into(),
+            description: String::new(),
+            kind: BlueprintKind::default(),
+            capacity: capacity_default(),
+            one_time: false,
+            single_use: false,
+            light: false,
+            author: String::new(),
+        }
+    }
+
+    /// Craft a brand new [Item] off of the blueprint.
+    pub fn instantiate(&self) -> Item {
+        match self.kind {
+            BlueprintKind::Container => Item::Container(Container::Backpack(Content::from(self))),
+            BlueprintKind::Melee => Item::Weapon(Weapon::Melee(self.into())),
+            BlueprintKind::Ranged => Item::Weapon(Weapon::Ranged(self.into())),
+            BlueprintKind::Tool => Item::Tool(Tool::from(self)),
+            BlueprintKind::Key => Item::Key(Key::from(self)),
+        }
+    }
+}
+
+impl From<&Item> for Blueprint {
+    /// Distill a (rough) [Blueprint] out of an existing [Item].
+    ///
+    /// Lossy — container instances don't carry a description, for one, so
+    /// prefer the registered blueprint whenever there is one.
+    fn from(value: &Item) -> Self {
+        let mut bp = Self::new(value.bp_id());
+        bp.title = value.title().into();
+        match value {
+            Item::Container(c) => {
+                bp.kind = BlueprintKind::Container;
+                bp.capacity = c.capacity();
+            },
+            Item::Weapon(w) => {
+                bp.kind = match w {
+                    Weapon::Melee(_) => BlueprintKind::Melee,
+                    Weapon::Ranged(_) => BlueprintKind::Ranged,
+                };
+                bp.description = w.description().into();
+            },
+            Item::Tool(t) => {
+                bp.kind = BlueprintKind::Tool;
+                bp.description = t.description().into();
+                bp.single_use = t.single_use;
+                bp.light = t.light;
+            },
+            Item::Key(k) => {
+                bp.kind = BlueprintKind::Key;
+                bp.description = k.description().into();
+                bp.one_time = k.one_time;
+            },
+        }
+        bp
+    }
+}
+
+#[async_trait::async_trait]
+impl DoesSave for Blueprint {
+    async fn save(&mut self) -> Result<(), SaveError> {
+        tokio::fs::create_dir_all(BLUEPRINT_PATH.as_str()).await?;
+        tokio::fs::write(format!("{}/{}.json", *BLUEPRINT_PATH, self.id), serde_json::to_string_pretty(self)?).await?;
+        Ok(())
+    }
+}
+
+/// All the known [Blueprint]s.
+#[derive(Debug, Default)]
+pub struct BlueprintRegistry {
+    blueprints: HashMap<String, Blueprint>,
+}
+
+impl BlueprintRegistry {
+    /// Load all blueprints from [BLUEPRINT_PATH].
+    ///
+    /// Malformed files are skipped (with a warning).
+    pub async fn load() -> Result<Self, std::io::Error> {
+        tokio::fs::create_dir_all(BLUEPRINT_PATH.as_str()).await?;
+        let mut blueprints = HashMap::new();
+        let mut dir = tokio::fs::read_dir(BLUEPRINT_PATH.as_str()).await?;
+        while let Some(entry) = dir.next_entry().await? {
+            let path = entry.path();
+            if !path.is_file() || path.extension().and_then(|s| s.to_str()) != Some("json") {
+                continue;
+            }
+            match serde_json::from_str::<Blueprint>(&tokio::fs::read_to_string(&path).await?) {
+                Ok(bp) => { blueprints.insert(bp.id.clone(), bp); },
+                Err(e) => log::warn!("Blueprint '{}' malformed: {e:?}", path.display()),
+            }
+        }
+        log::info!("{} blueprint(s) loaded.", blueprints.len());
+        Ok(Self { blueprints })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Blueprint> {
+        self.blueprints.get(id)
+    }
+
+    /// May `player` (over)write blueprint `id`?
+    ///
+    /// New IDs are up for grabs; existing ones belong to their author — and admins.
+    pub fn may_edit(&self, player: &Player, id: &str) -> bool {
+        match self.blueprints.get(id) {
+            None => true,
+            Some(_) if player.access.is_admin() => true,
+            Some(bp) => !bp.author.is_empty() && bp.author == player.id()
+        }
+    }
+
+    pub fn insert(&mut self, blueprint: Blueprint) {
+        self.blueprints.insert(blueprint.id.clone(), blueprint);
+    }
+
+    /// All blueprints, sorted by ID.
+    pub fn list(&self) -> Vec<&Blueprint> {
+        let mut list = self.blueprints.values().collect::<Vec<_>>();
+        list.sort_by(|a, b| a.id.cmp(&b.id));
+        list
+    }
+}
+
+#[cfg(test)]
+mod blueprint_tests {
+    use crate::traits::Owned;
+
+    use super::*;
+
+    #[test]
+    fn instantiate_each_kind() {
+        for kind in BlueprintKind::ALL {
+            let mut bp = Blueprint::new("thingamajig");
+            bp.kind = kind;
+            bp.title = "a thingamajig".into();
+            bp.description = "It does things.".into();
+            let item = bp.instantiate();
+            assert_eq!("thingamajig", item.bp_id());
+            assert_ne!("thingamajig", item.id());
+            assert_eq!("a thingamajig", item.title());
+            assert!(!item.is_owned());
+            // and back again.
+            let back = Blueprint::from(&item);
+            assert_eq!(kind, back.kind);
+            if kind != BlueprintKind::Container {
+                assert_eq!("It does things.", back.description);
+            }
+        }
+    }
+
+    #[test]
+    fn instantiate_with_specs() {
+        let mut bp = Blueprint::new("pouch");
+        bp.kind = BlueprintKind::Container;
+        bp.capacity = 2;
+        let mut pouch = bp.instantiate();
+        assert_eq!(2, pouch.capacity());
+        bp.kind = BlueprintKind::Key;
+        bp.one_time = true;
+        let key = bp.instantiate();
+        assert!(matches!(&key, Item::Key(k) if k.one_time));
+        assert!(pouch.try_insert(key).is_ok());
+
+        bp.kind = BlueprintKind::Tool;
+        bp.light = true;
+        assert!(bp.instantiate().is_light());
+        // unique IDs per instance
+        assert_ne!(bp.instantiate().id(), bp.instantiate().id());
+    }
+
+    #[test]
+    fn only_author_may_edit() {
+        let mut reg = BlueprintRegistry::default();
+        let mut bp = Blueprint::new("spoon");
+        bp.author = "alice".into();
+        reg.insert(bp);
+        reg.insert(Blueprint::new("fork"));
+        let (alice, bob) = (Player::new("alice"), Player::new("bob"));
+        assert!(reg.may_edit(&alice, "spoon"));
+        assert!(!reg.may_edit(&bob, "spoon"));
+        assert!(reg.may_edit(&bob, "knife"));
+        // authorless ones are for admins only.
+        assert!(!reg.may_edit(&alice, "fork"));
+    }
+
+    #[test]
+    fn kind_from_str() {
+        assert_eq!(Ok(BlueprintKind::Ranged), BlueprintKind::try_from("RANGED"));
+        assert!(BlueprintKind::try_from("spoon").is_err());
+    }
+}
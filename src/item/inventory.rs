@@ -215,6 +215,21 @@ impl Owned for Container {
     }
 }
 
+impl Container {
+    /// Issue fresh IDs for the container and everything within.
+    ///
+    /// [Room] containers are tied to their room's ID and thus left alone.
+    ///
+    /// [Room]: crate::world::room::Room
+    pub(crate) fn reissue_id(&mut self) {
+        match self {
+            Self::Backpack(c)|
+            Self::PlayerInventory(c) => c.reissue_id(),
+            Self::Room(_) => ()
+        }
+    }
+}
+
 #[cfg(feature = "localtest")]
 impl Container {
     pub(crate) fn set_id(&mut self, id: &str) {
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{item::{BlueprintID, Item, ItemError, ItemMap, blueprint::BACKPACK_BP_ID, inventory::{ContainerType, Storage, StorageCapacity, storage::StorageIdentity}}, player::pc::MAX_ITEMS_PLAYER_INVENTORY, string::uuid_id::AsUuidId, traits::{IdentityQuery, Owned, owned::{Owner, OwnerError}}, world::room::MAX_ITEMS_IN_ROOM};
+use crate::{item::{BlueprintID, Item, ItemError, ItemMap, blueprint::{BACKPACK_BP_ID, Blueprint}, inventory::{ContainerType, Storage, StorageCapacity, storage::StorageIdentity}}, player::pc::MAX_ITEMS_PLAYER_INVENTORY, string::uuid_id::AsUuidId, traits::{IdentityQuery, Owned, owned::{Owner, OwnerError}}, world::room::MAX_ITEMS_IN_ROOM};
 
 fn title_default() -> String { "container".into() }
 
@@ -76,6 +76,19 @@ impl From<ContainerType> for Content {
     }
 }
 
+impl From<&Blueprint> for Content {
+    fn from(value: &Blueprint) -> Self {
+        Self {
+            id: value.id.uuided(),
+            bp_id: value.id.clone(),
+            title: value.title.clone(),
+            owner: Owner::default(),
+            max_capacity: value.capacity,
+            contents: HashMap::new()
+        }
+    }
+}
+
 impl Storage for Content {
     fn try_insert(&mut self, item: Item) -> Result<(), ItemError> {
         if !self.is_container() {
@@ -200,6 +213,16 @@ impl Storage for Content {
     }
 }
 
+impl Content {
+    /// Issue fresh IDs for self and everything within.
+    pub(crate) fn reissue_id(&mut self) {
+        self.id = self.bp_id.uuided();
+        self.contents = std::mem::take(&mut self.contents).into_values()
+            .map(|mut item| { item.reissue_id(); (item.id().to_string(), item) })
+            .collect();
+    }
+}
+
 impl Owned for Content {
     fn owner(&self) -> &str { self.owner.owner() }
     fn original_owner(&self) -> &str { self.owner.original_owner() }
@@ -85,6 +85,21 @@ impl Item {
         }
     }
 
+    /// Issue a fresh ID for the item, e.g. for a copy spawned off of a template.
+    pub(crate) fn reissue_id(&mut self) {
+        match self {
+            Self::Container(c) => c.reissue_id(),
+            Self::Weapon(w) => w.reissue_id(),
+            Self::Tool(t) => t.reissue_id(),
+            Self::Key(k) => k.reissue_id(),
+        }
+    }
+
+    /// Does the item work as a light source?
+    pub fn is_light(&self) -> bool {
+        matches!(self, Self::Tool(t) if t.light)
+    }
+
     /// Re-ID the item.
     #[cfg(test)]
     pub(crate) fn re_id(mut self) -> Self {
@@ -225,7 +240,13 @@ impl From<ItemError> for Item {
 #[macro_export]
 macro_rules! force_item_to_player {
     ($ctx:ident, $item:ident) => {{
-        let e = $ctx.player.write().await.inventory.try_insert($item);
+        let gained = $crate::player::journal::JournalEntry::Gained($item.clone());
+        let mut p = $ctx.player.write().await;
+        let e = p.inventory.try_insert($item);
+        if e.is_ok() {
+            p.journal(gained);
+        }
+        drop(p);
         if let Err(e) = e {
             log::error!("Could NOT force item to player '{}': {e}", $ctx.player.read().await.id());
         }
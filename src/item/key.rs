@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{item::BlueprintID, traits::{Description, IdentityQuery, Owned, owned::{Owner, OwnerError}}};
+use crate::{item::{BlueprintID, blueprint::Blueprint}, traits::{Description, IdentityQuery, Owned, owned::{Owner, OwnerError}}};
 
 // TODO: naming creativity!.
 fn title_default() -> String { "a key".into() }
@@ -46,6 +46,22 @@ impl Key {
     }
 }
 
+impl From<&Blueprint> for Key {
+    fn from(value: &Blueprint) -> Self {
+        let mut key = Self::new(&value.id, value.one_time);
+        key.title = value.title.clone();
+        key.description = value.description.clone();
+        key
+    }
+}
+
+impl Key {
+    pub(crate) fn reissue_id(&mut self) {
+        use crate::string::uuid_id::AsUuidId;
+        self.id = self.bp_id.uuided();
+    }
+}
+
 impl Owned for Key {
     fn owner(&self) -> &str { self.owner.owner() }
     fn original_owner(&self) -> &str { self.owner.original_owner() }
@@ -2,16 +2,21 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{item::BlueprintID, traits::{IdentityQuery, Owned, owned::*}};
+use crate::{item::{BlueprintID, blueprint::Blueprint}, traits::{Description, IdentityQuery, Owned, owned::*}};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Tool {
     id: String,
     title: String,
     #[serde(default)]
+    description: String,
+    #[serde(default)]
     owner: Owner,
     bp_id: String,
     pub single_use: bool,
+    /// Does it shed light?
+    #[serde(default)]
+    pub light: bool,
 }
 
 impl BlueprintID for Tool {
@@ -23,6 +28,32 @@ impl IdentityQuery for Tool {
     fn title<'a>(&'a self) -> &'a str { &self.title }
 }
 
+impl From<&Blueprint> for Tool {
+    fn from(value: &Blueprint) -> Self {
+        use crate::string::uuid_id::AsUuidId;
+        Self {
+            id: value.id.uuided(),
+            title: value.title.clone(),
+            description: value.description.clone(),
+            owner: Owner::default(),
+            bp_id: value.id.clone(),
+            single_use: value.single_use,
+            light: value.light,
+        }
+    }
+}
+
+impl Tool {
+    pub(crate) fn reissue_id(&mut self) {
+        use crate::string::uuid_id::AsUuidId;
+        self.id = self.bp_id.uuided();
+    }
+}
+
+impl Description for Tool {
+    fn description(&self) -> &str { &self.description }
+}
+
 impl Owned for Tool {
     fn owner(&self) -> &str { self.owner.owner() }
     fn original_owner(&self) -> &str { self.owner.original_owner() }
@@ -0,0 +1,155 @@
+//! Lost-and-found vault.
+//!
+//! Items that could not be put anywhere (full inventories, vanished rooms, etc.)
+//! end up in [World::lost_and_found][crate::world::World::lost_and_found]. The
+//! `io_loop` collects them from there into the [Vault], which is persisted to
+//! disk so that nothing saved from The Void is lost on restart.
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::{DATA_PATH, item::{Item, ItemError}, traits::{IdentityQuery, Owned, save::{DoesSave, SaveError}}};
+
+pub(crate) static VAULT_FILEPATH: Lazy<String> = Lazy::new(|| format!("{}/lost_and_found.json", *DATA_PATH));
+pub(crate) static VAULT: Lazy<Arc<RwLock<Vault>>> = Lazy::new(|| Arc::new(RwLock::new(Vault::default())));
+/// How long items are kept around before they're considered gone for good.
+pub(crate) const VAULT_RETENTION_SECS: u64 = 30 * 86_400;// 30 days
+
+/// A single item held in the [Vault].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VaultEntry {
+    pub item: Item,
+    /// Why the item ended up here.
+    pub reason: String,
+    /// When the item ended up here (UNIX seconds).
+    pub stored_at: u64,
+}
+
+impl VaultEntry {
+    /// Seconds until expiry, if not already expired.
+    pub fn expires_in(&self, now: u64) -> u64 {
+        (self.stored_at + VAULT_RETENTION_SECS).saturating_sub(now)
+    }
+}
+
+/// The vault itself.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Vault {
+    entries: HashMap<String, VaultEntry>,
+}
+
+impl Vault {
+    /// Load the vault from disk, or start with an empty one.
+    pub async fn load() -> Self {
+        let content = match tokio::fs::read_to_string(VAULT_FILEPATH.as_str()).await {
+            Ok(content) => content,
+            Err(_) => {
+                log::info!("No lost-and-found vault at '{}' (yet).", *VAULT_FILEPATH);
+                return Self::default();
+            }
+        };
+        match serde_json::from_str::<Self>(&content) {
+            Ok(vault) => {
+                log::info!("Lost-and-found vault holds {} item{}.", vault.len(), if vault.len() == 1 {""} else {"s"});
+                vault
+            },
+            Err(e) => {
+                log::error!("Lost-and-found vault '{}' malformed: {e:?}", *VAULT_FILEPATH);
+                Self::default()
+            }
+        }
+    }
+
+    /// Stash an item carried by `err` into the vault.
+    ///
+    /// # Returns
+    /// `false` if there was no item to stash.
+    pub fn stash(&mut self, err: ItemError, now: u64) -> bool {
+        let reason = err.to_string();
+        let item = match err {
+            ItemError::NoSpace(i)|
+            ItemError::NotContainer(i)|
+            ItemError::TooLarge(i) => i,
+            ItemError::NotFound => return false,
+        };
+        self.entries.insert(item.id().into(), VaultEntry { item, reason, stored_at: now });
+        true
+    }
+
+    /// Drop entries older than the retention window.
+    ///
+    /// # Returns
+    /// IDs of the expired entries.
+    pub fn prune(&mut self, now: u64) -> Vec<String> {
+        let expired = self.entries.iter()
+            .filter(|(_, e)| e.expires_in(now) == 0)
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<String>>();
+        for id in &expired {
+            self.entries.remove(id);
+        }
+        expired
+    }
+
+    /// Find entry ID exactly or by partial match.
+    pub fn find_id(&self, id: &str) -> Option<String> {
+        let id = id.trim().to_lowercase();
+        if self.entries.contains_key(&id) {
+            return Some(id);
+        }
+        self.entries.keys().find(|k| k.contains(&id)).cloned()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&VaultEntry> { self.entries.get(id) }
+
+    /// Take an entry out of the vault.
+    pub fn take(&mut self, id: &str) -> Option<VaultEntry> { self.entries.remove(id) }
+
+    /// Put a (previously taken) entry back in, as-is.
+    pub fn restore(&mut self, entry: VaultEntry) {
+        self.entries.insert(entry.item.id().into(), entry);
+    }
+
+    /// List entries, optionally only those (originally or currently) owned by `owner`.
+    pub fn list(&self, owner: Option<&str>) -> Vec<&VaultEntry> {
+        let mut list = self.entries.values()
+            .filter(|e| owner.is_none_or(|o| e.item.original_owner() == o || e.item.owner() == o))
+            .collect::<Vec<&VaultEntry>>();
+        list.sort_by_key(|e| e.stored_at);
+        list
+    }
+
+    pub fn len(&self) -> usize { self.entries.len() }
+}
+
+#[async_trait]
+impl DoesSave for Vault {
+    async fn save(&mut self) -> Result<(), SaveError> {
+        let tmp = format!("{}.tmp", *VAULT_FILEPATH);
+        tokio::fs::write(&tmp, serde_json::to_string_pretty(self)?).await?;
+        tokio::fs::rename(&tmp, VAULT_FILEPATH.as_str()).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod vault_tests {
+    use crate::item::{item::ItemType, Item};
+    use super::*;
+
+    #[test]
+    fn stash_and_expire() {
+        let mut vault = Vault::default();
+        let item = Item::new(ItemType::Weapon);
+        let id = item.id().to_string();
+        assert!(vault.stash(ItemError::NoSpace(item), 1_000));
+        assert!(!vault.stash(ItemError::NotFound, 1_000));
+        assert_eq!(Some(id.clone()), vault.find_id(&id[..8]));
+        assert!(vault.prune(1_000 + VAULT_RETENTION_SECS - 1).is_empty());
+        assert_eq!(vec![id], vault.prune(1_000 + VAULT_RETENTION_SECS));
+        assert_eq!(0, vault.len());
+    }
+}
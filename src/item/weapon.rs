@@ -60,6 +60,13 @@ impl Weapon {
         };
         self
     }
+
+    pub(crate) fn reissue_id(&mut self) {
+        match self {
+            Self::Melee(m) => m.reissue_id(),
+            Self::Ranged(r) => r.reissue_id(),
+        }
+    }
 }
 
 impl Owned for Weapon {
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{item::{BlueprintID, blueprint::MELEE_BP_ID}, string::uuid_id::AsUuidId, traits::{Description, IdentityQuery, Owned, owned::{Owner, OwnerError}}};
+use crate::{item::{BlueprintID, blueprint::{Blueprint, MELEE_BP_ID}}, string::uuid_id::AsUuidId, traits::{Description, IdentityQuery, Owned, owned::{Owner, OwnerError}}};
 
 // TODO: naming creativity!
 fn title_default() -> String { "melee weapon".into() }
@@ -34,12 +34,28 @@ impl Default for MeleeInfo {
     }
 }
 
+impl From<&Blueprint> for MeleeInfo {
+    fn from(value: &Blueprint) -> Self {
+        Self {
+            id: value.id.uuided(),
+            bp_id: value.id.clone(),
+            owner: Owner::default(),
+            title: value.title.clone(),
+            description: value.description.clone(),
+        }
+    }
+}
+
 impl MeleeInfo {
     #[cfg(test)]
     pub(crate) fn re_id(&mut self) -> &mut Self {
         self.id = MELEE_BP_ID.uuided();
         self
     }
+
+    pub(crate) fn reissue_id(&mut self) {
+        self.id = self.bp_id.uuided();
+    }
 }
 
 impl Owned for MeleeInfo {
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{item::{BlueprintID, blueprint::RANGED_BP_ID}, string::uuid_id::AsUuidId, traits::{Description, IdentityQuery, Owned, owned::{Owner, OwnerError}}};
+use crate::{item::{BlueprintID, blueprint::{Blueprint, RANGED_BP_ID}}, string::uuid_id::AsUuidId, traits::{Description, IdentityQuery, Owned, owned::{Owner, OwnerError}}};
 
 // TODO: naming creativity!
 fn title_default() -> String { "ranged weapon of some sort".into() }
@@ -34,6 +34,24 @@ impl Default for RangedInfo {
     }
 }
 
+impl From<&Blueprint> for RangedInfo {
+    fn from(value: &Blueprint) -> Self {
+        Self {
+            id: value.id.uuided(),
+            bp_id: value.id.clone(),
+            owner: Owner::default(),
+            title: value.title.clone(),
+            description: value.description.clone(),
+        }
+    }
+}
+
+impl RangedInfo {
+    pub(crate) fn reissue_id(&mut self) {
+        self.id = self.bp_id.uuided();
+    }
+}
+
 impl Owned for RangedInfo {
     fn owner(&self) -> &str { self.owner.owner() }
     fn original_owner(&self) -> &str { self.owner.original_owner() }
@@ -3,12 +3,12 @@
 //! See README.md ...
 //! 
 //! The `main()` is a monster, but it's a friendly monster ;-)
-use std::{collections::HashSet, ops::Deref, sync::Arc};
+use std::{collections::HashSet, net::SocketAddr, ops::Deref, os::fd::AsRawFd, sync::Arc};
 use clap::Parser;
 use once_cell::sync::{Lazy, OnceCell};
 use tokio::{
     io::{AsyncBufReadExt, BufReader},
-    net::TcpListener,
+    net::{TcpListener, TcpStream},
     sync::{broadcast, RwLock}
 };
 
@@ -24,7 +24,7 @@ mod cmd;
 mod item;
 mod test;
 
-use crate::{cmd::{CommandCtx, force::ForceSource, help::HELP_REGISTRY, translocate::translocate}, io::DEFAULT_AUTOSAVE_QUEUE_INTERVAL, string::WordSet, traits::{Description, IdentityQuery, mob::IsMob}, util::{Broadcast, ClientState, comm::{IsRecipient, MessagePayload, SystemBroadcastType}, help::Help}};
+use crate::{cmd::{CommandCtx, force::ForceSource, help::HELP_REGISTRY, translocate::translocate}, io::DEFAULT_AUTOSAVE_QUEUE_INTERVAL, item::blueprint::{BLUEPRINTS, BlueprintRegistry}, mob::template::{MOB_TEMPLATES, MobRegistry}, string::WordSet, traits::{Description, IdentityQuery, mob::IsMob}, util::{Broadcast, ClientState, comm::{IsRecipient, MessagePayload, SystemBroadcastType}, copyover, direction::custom::{CUSTOM_DIRECTIONS, CustomDirections}, help::Help}, world::{SharedWorld, integrity, room::template::{ROOM_TEMPLATES, RoomTemplateRegistry}}};
 use crate::player::{access::Access, LoadError, Player};
 use crate::string::{prompt::PromptType, sanitize::Sanitizer};
 use crate::traits::save::DoesSave;
@@ -69,34 +69,46 @@ pub(crate) struct CmdLineArgs {
     #[arg(long, env = "RUSTROM_DATA", default_value = "data")]  data_path: String,
     #[arg(long)]                                                bootstrap_url: Option<String>,
     #[arg(long)]                                                autosave_queue_interval: Option<u64>,
+    /// Session file left behind by a copyover; not for human consumption.
+    #[arg(long, hide = true)]                                   copyover: Option<String>,
 }
 
+// some constants to deal with [World]-specific choices that aren't present for a reason or other…
+const GREETING: &str = "Welcome to RustROM!";
+const PROMPT_LOGIN: &str = "What do we call you?: ";
+const PROMPT_PASSWD1: &str = "Password: ";
+const PROMPT_PASSWDV: &str = "Re-type same password: ";
+const WELCOME_BACK: &str = "Welcome back!";
+const WELCOME_NEW: &str = "May your adventures be prosperous!";
+
 /// Main entrance.
 //
 // Main is a bit monstrous place, but bear with it.
 //
 #[tokio::main]
 async fn main() {
-    // some constants to deal with [World]-specific choices that aren't present for a reason or other…
-    const GREETING: &str = "Welcome to RustROM!";
-    const PROMPT_LOGIN: &str = "What do we call you?: ";
-    const PROMPT_PASSWD1: &str = "Password: ";
-    const PROMPT_PASSWDV: &str = "Re-type same password: ";
-    const WELCOME_BACK: &str = "Welcome back!";
-    const WELCOME_NEW: &str = "May your adventures be prosperous!";
-
     let args = CmdLineArgs::parse();
     if let Some(duration) = args.autosave_queue_interval {
         *AUTOSAVE_QUEUE_INTERVAL.write().await = duration;
     }
     // Note that DATA has to be set *before* any I/O is initiated.
     let _ = DATA.set(args.data_path);
+    player::journal::start(&player::pc::SAVE_PATH);
 
     // Initialize the logger
     env_logger::init();
 
+    // Custom directions, before anything gets to show one …
+    let _ = CUSTOM_DIRECTIONS.set(CustomDirections::load().await.expect("ERROR: custom directions unreadable?!"));
+
+    // Were we exec'd by a copyover?
+    let carried_over = args.copyover.as_deref().map(|f| copyover::read_session_file(f).expect("ERROR: copyover session file unreadable?!"));
+
     let bad_words: Arc<RwLock<WordSet>> = Arc::new(RwLock::new(HashSet::new()));
 
+    // Item blueprints, before the world — the integrity check wants to know what keys exist …
+    *BLUEPRINTS.write().await = BlueprintRegistry::load().await.expect("ERROR: item blueprints unreadable?!");
+
     // Load the world ...
     let world = Arc::new(RwLock::new(
         World::load_or_bootstrap(&args.world).await.expect("ERROR: world dead or in fire?!")
@@ -129,8 +141,19 @@ async fn main() {
             (_, None) => panic!("'root' room is a miss!?"),
             _ => ()// yay!
         }
+
+        // …and a more thorough one, for the record.
+        let issues = integrity::check(&w).await;
+        for issue in &issues {
+            log::warn!("Integrity: {}", issue);
+        }
+        log::info!("Integrity check done, {} issue{} found.", issues.len(), if issues.len() == 1 {""} else {"s"});
     }
 
+    // Mob templates …
+    *MOB_TEMPLATES.write().await = MobRegistry::load().await.expect("ERROR: mob templates unreadable?!");
+    // Room templates …
+    *ROOM_TEMPLATES.write().await = RoomTemplateRegistry::load().await.expect("ERROR: room templates unreadable?!");
     // Bootstrap and load [Help] entries …
     Help::bootstrap(args.bootstrap_url).await.expect("Bootstrapping failed?!");
     let (help_core, help_aliases) = Help::load_all().await.expect("Oopsie - we're helpless - no help available?!");
@@ -138,306 +161,368 @@ async fn main() {
         RwLock::new((help_core, help_aliases))
     });
 
-    tokio::spawn(game_loop(world.clone()));
+    // A broadcast channel is used to send messages to all connected clients.
+    // Here, we're just broadcasting chat messages.
+    let (tx, _) = broadcast::channel::<Broadcast>(16);
+
+    tokio::spawn(game_loop(world.clone(), tx.clone()));
     tokio::spawn(io_loop(world.clone(), bad_words.clone()));
 
-    // Create a listener that will accept incoming connections.
+    // Create a listener that will accept incoming connections — or inherit
+    // one from our previous incarnation in case of a copyover.
     let listen_on = format!("{}:{}", args.host_listen_addr, args.port);
-    let listener = TcpListener::bind(&listen_on).await.unwrap();
+    let listener = match &carried_over {
+        Some(co) => copyover::inherit_listener(co.listener_fd).expect("ERROR: copyover listener went missing?!"),
+        None => TcpListener::bind(&listen_on).await.unwrap()
+    };
+    world.write().await.listener_fd = Some(listener.as_raw_fd());
     log::info!("Server listening on {}", listen_on);
 
-    // A broadcast channel is used to send messages to all connected clients.
-    // Here, we're just broadcasting chat messages.
-    let (tx, _) = broadcast::channel::<Broadcast>(16);
-    
+    // Pick up sessions that were carried over a copyover, if any.
+    if let Some(co) = carried_over {
+        for session in co.sessions {
+            match copyover::resume_session(&session, &world).await {
+                Ok((socket, state)) => {
+                    log::info!("Session of '{}' carried over.", session.player);
+                    tokio::spawn(handle_client(socket, session.addr, world.clone(), tx.clone(), bad_words.clone(), Some(state)));
+                },
+                Err(e) => log::error!("Could not carry over session of '{}': {e:?}", session.player),
+            }
+        }
+    }
+
     loop {
         // Wait for a new client to connect.
         let (socket, addr) = listener.accept().await.unwrap();
         log::info!("New connection from: {}", addr);
 
-        // Clone the sender part of the broadcast channel for the new client.
-        let tx = tx.clone();
-
-        // Get a receiver for this client to listen for messages from others.
-        let mut rx = tx.subscribe();
-        let world = world.clone();
-        let bad_words = bad_words.clone();
-
         // Spawn a new task to handle this client's connection,
         // which lets us to handle multiple clients concurrently.
-        tokio::spawn(async move {
-            // Split the socket into a reader and a writer.
-            let (reader, mut writer) = socket.into_split();
-
-            // Use a BufReader for efficient line-by-line reading.
-            let mut reader = BufReader::new(reader);
-            let mut line = String::new();
-
-            // Send a welcome message to the new client.
-            let (greeting, login_prompt) = {
-                let w = world.read().await;
-                let g = w.greeting.clone().unwrap_or_else(|| GREETING.to_string());
-                let p = w.prompts.get(&PromptType::Login).cloned().unwrap_or_else(|| PROMPT_LOGIN.to_string());
-                (g, p)
-            };
+        tokio::spawn(handle_client(socket, addr, world.clone(), tx.clone(), bad_words.clone(), None));
+    }
+}
+
+/// Serve a single client connection, from greeting to goodbye.
+///
+/// # Arguments
+/// - `socket` and `addr` of the client.
+/// - `world`— the [SharedWorld].
+/// - `tx`— broadcast channel sender.
+/// - `bad_words`— for name checks.
+/// - `resumed`— [ClientState] of a session carried over a copyover, if any.
+async fn handle_client(
+    socket: TcpStream,
+    addr: SocketAddr,
+    world: SharedWorld,
+    tx: broadcast::Sender<Broadcast>,
+    bad_words: Arc<RwLock<WordSet>>,
+    resumed: Option<ClientState>,
+) {
+    // Get a receiver for this client to listen for messages from others.
+    let mut rx = tx.subscribe();
+    // Keep tabs on the raw socket, for copyover's sake.
+    world.write().await.session_fds.insert(addr, socket.as_raw_fd());
+
+    // Split the socket into a reader and a writer.
+    let (reader, mut writer) = socket.into_split();
+
+    // Use a BufReader for efficient line-by-line reading.
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+
+    // Send a welcome message to the new client.
+    let (greeting, login_prompt) = {
+        let w = world.read().await;
+        let g = w.greeting.clone().unwrap_or_else(|| GREETING.to_string());
+        let p = w.prompts.get(&PromptType::Login).cloned().unwrap_or_else(|| PROMPT_LOGIN.to_string());
+        (g, p)
+    };
+    let mut state = match resumed {
+        // Session carried over a copyover — just have a look around.
+        Some(state) => {
+            let p = world.read().await.players_by_sockaddr.get(&addr).cloned();
+            match p {
+                Some(p) => {
+                    tell_user!(&mut writer, "\n<c green>… and we're back.</c>\n\n");
+                    let ctx = CommandCtx {
+                        player: p.clone(),
+                        state: state.clone(),
+                        world: &world,
+                        tx: &tx,
+                        args: "look",
+                        writer: &mut writer,
+                        };
+                    let state = cmd::parse_and_execute(ctx).await;
+                    tell_user!(&mut writer, p.read().await.prompt().await);
+                    state
+                },
+                None => {
+                    log::error!("Carried over session '{}' has no player?!", addr);
+                    tell_user!(&mut writer, "{}\n\n{}", greeting, &login_prompt);
+                    ClientState::EnteringName
+                }
+            }
+        },
+        None => {
             tell_user!(&mut writer, "{}\n\n{}", greeting, &login_prompt);
+            ClientState::EnteringName
+        }
+    };
+    let mut abrupt_dc = false;
 
-            let mut state = ClientState::EnteringName;
-            let mut abrupt_dc = false;
-
-            //=======================================
-            //
-            // This is the main-loop for the client.
-            //
-            loop {
-                // Check if [Player] is logging out (due disconnect or otherwise)…
-                if let ClientState::Logout = &state {
-                    let mut w = world.write().await;
-                    if let Some(p) = w.players_by_sockaddr.remove(&addr) {
-                        // drop the named mapping here as it's not needed for logout.
-                        let lock = p.read().await;
-                        w.players.remove(lock.id());
-                        if !abrupt_dc {
-                            tell_user!(&mut writer, "\n<c cyan>Goodbye {}! See you soon again!</c>\n", lock.id());
-                        }
-                        drop(lock);
-                        w.players_to_logout.push(p);
+    //=======================================
+    //
+    // This is the main-loop for the client.
+    //
+    loop {
+        // Check if [Player] is logging out (due disconnect or otherwise)…
+        if let ClientState::Logout = &state {
+            let mut w = world.write().await;
+            if let Some(p) = w.players_by_sockaddr.remove(&addr) {
+                // drop the named mapping here as it's not needed for logout.
+                let lock = p.read().await;
+                w.players.remove(lock.id());
+                if !abrupt_dc {
+                    tell_user!(&mut writer, "\n<c cyan>Goodbye {}! See you soon again!</c>\n", lock.id());
+                }
+                drop(lock);
+                w.players_to_logout.push(p);
+            }
+            break;
+        }
+
+        // IMPORTANT: wipe the buffer before each read_line() as instead of
+        //            clearing the buffer on its own, read_line() keeps
+        //            accumulating onto it… we'd run out of memory sooner
+        //            or later.
+        line.clear();// ← !!!
+
+        tokio::select! {
+            // --- First Branch: Read input from the client ---
+            result = reader.read_line(&mut line) => {
+                // An abrupt disconnect?
+                if result.unwrap_or(0) == 0 {
+                    log::info!("Client {} disconnected abruptly.", addr);
+                    if state.is_in_game() {
+                        abrupt_dc = true;
+                        state = ClientState::Logout;
+                        continue;
                     }
-                    break;
+                    break; // not in game, cut the line, wipe the floors and take a break.
                 }
 
-                // IMPORTANT: wipe the buffer before each read_line() as instead of
-                //            clearing the buffer on its own, read_line() keeps
-                //            accumulating onto it… we'd run out of memory sooner
-                //            or later.
-                line.clear();// ← !!!
-
-                tokio::select! {
-                    // --- First Branch: Read input from the client ---
-                    result = reader.read_line(&mut line) => {
-                        // An abrupt disconnect?
-                        if result.unwrap_or(0) == 0 {
-                            log::info!("Client {} disconnected abruptly.", addr);
-                            if state.is_in_game() {
-                                abrupt_dc = true;
-                                state = ClientState::Logout;
-                                continue;
+                let input = line.trim().sanitize();
+
+                // Handle player input based on their current [ClientState].
+                state = match state {
+                    ClientState::EnteringName => {
+                        if input.is_empty() {
+                            tell_user!(&mut writer, &login_prompt);
+                            state
+                        } else {
+                            log::info!("Login attempt on '{}'…", input);
+                            let can_continue = if world.read().await.players.contains_key(&input) {
+                                false
+                            } else if let Err(LoadError::InvalidName) = Player::load_is_possible(bad_words.clone(), &input).await {
+                                false
+                            } else {
+                                true
+                            };
+
+                            if can_continue {
+                                tell_user!(&mut writer, get_prompt!(world, PromptType::Password1, PROMPT_PASSWD1));
+                                ClientState::EnteringPassword1 { name: input.to_string() }
+                            } else {
+                                tell_user!(&mut writer, "Name '{}' is reserved, please try another.\n\n{}", input, &login_prompt);
+                                ClientState::EnteringName
                             }
-                            break; // not in game, cut the line, wipe the floors and take a break.
                         }
+                    },
 
-                        let input = line.trim().sanitize();
-
-                        // Handle player input based on their current [ClientState].
-                        state = match state {
-                            ClientState::EnteringName => {
-                                if input.is_empty() {
-                                    tell_user!(&mut writer, &login_prompt);
-                                    state
-                                } else {
-                                    log::info!("Login attempt on '{}'…", input);
-                                    let can_continue = if world.read().await.players.contains_key(&input) {
-                                        false
-                                    } else if let Err(LoadError::InvalidName) = Player::load_is_possible(bad_words.clone(), &input).await {
-                                        false
-                                    } else {
-                                        true
-                                    };
-
-                                    if can_continue {
-                                        tell_user!(&mut writer, get_prompt!(world, PromptType::Password1, PROMPT_PASSWD1));
-                                        ClientState::EnteringPassword1 { name: input.to_string() }
-                                    } else {
-                                        tell_user!(&mut writer, "Name '{}' is reserved, please try another.\n\n{}", input, &login_prompt);
-                                        ClientState::EnteringName
+                    ClientState::EnteringPassword1{ name } => {
+                        match Player::load(&name, &input, &addr).await {
+                            Ok(mut save) => {
+                                let mut translocated = false;
+                                log::info!("'{}' successfully logged in.", name);
+                                let (msg, prompt) = {
+                                    save.erase_states(ClientState::Playing);
+                                    let p = Arc::new(RwLock::new(save));
+
+                                    let location = p.read().await.location.clone();
+                                    let root_room = world.read().await.root.room.clone();
+                                    if !world.read().await.rooms.contains_key(&location) {
+                                        let pg = p.read().await;
+                                        log::warn!("Player '{}' location '{}' invalid. Translocating to safety of '{}'.", pg.id(), location, root_room);
+                                        translocated = true;
                                     }
-                                }
+                                    let source = location;
+                                    // Relocate player in case their saved location has evaporated...
+                                    let _ = translocate(&world, Some(source), root_room, p.clone()).await;
+                                    let mut w = world.write().await;
+                                    w.players_by_sockaddr.insert(addr.clone(), p.clone());
+                                    let prompt = {
+                                        let pl = p.read().await;
+                                        w.players.insert(pl.id().into(), p.clone());
+                                        pl.prompt().await
+                                    };
+                                    (w.welcome_back.clone().unwrap_or_else(|| WELCOME_BACK.to_string()), prompt)
+                                };
+                                tell_user!(&mut writer, "{}\n\n{}{}",
+                                    msg,
+                                    if translocated {
+                                        format!("You notice something... odd - you're not where you were before... But such happens, apparently.\n\n")
+                                    } else {"".into()},
+                                    prompt,
+                                );
+                                ClientState::Playing
                             },
-
-                            ClientState::EnteringPassword1{ name } => {
-                                match Player::load(&name, &input, &addr).await {
-                                    Ok(mut save) => {
-                                        let mut translocated = false;
-                                        log::info!("'{}' successfully logged in.", name);
-                                        let (msg, prompt) = {
-                                            save.erase_states(ClientState::Playing);
-                                            let p = Arc::new(RwLock::new(save));
-
-                                            let location = p.read().await.location.clone();
-                                            let root_room = world.read().await.root.room.clone();
-                                            if !world.read().await.rooms.contains_key(&location) {
-                                                let pg = p.read().await;
-                                                log::warn!("Player '{}' location '{}' invalid. Translocating to safety of '{}'.", pg.id(), location, root_room);
-                                                translocated = true;
-                                            }
-                                            let source = location;
-                                            // Relocate player in case their saved location has evaporated...
-                                            let _ = translocate(&world, Some(source), root_room, p.clone()).await;
-                                            let mut w = world.write().await;
-                                            w.players_by_sockaddr.insert(addr.clone(), p.clone());
-                                            let prompt = {
-                                                let pl = p.read().await;
-                                                w.players.insert(pl.id().into(), p.clone());
-                                                pl.prompt().await
-                                            };
-                                            (w.welcome_back.clone().unwrap_or_else(|| WELCOME_BACK.to_string()), prompt)
-                                        };
-                                        tell_user!(&mut writer, "{}\n\n{}{}",
-                                            msg,
-                                            if translocated {
-                                                format!("You notice something... odd - you're not where you were before... But such happens, apparently.\n\n")
-                                            } else {"".into()},
-                                            prompt,
-                                        );
-                                        ClientState::Playing
-                                    },
-                                    Err(LoadError::InvalidName) => {
-                                        tell_user!(&mut writer, "Name '{}' is reserved, please try another.\n\n{}", name, get_prompt!(world, PromptType::Login, PROMPT_LOGIN));
-                                        ClientState::EnteringName
-                                    },
-                                    Err(LoadError::NoSuchSave) => {
-                                        tell_user!(&mut writer, "{}", get_prompt!(world, PromptType::PasswordV, PROMPT_PASSWDV));
-                                        ClientState::EnteringPasswordV { name, pw1: input }
-                                    },
-                                    Err(e) => {
-                                        log::warn!("Failed login attempt for '{}': {:?}", name, e);
-                                        tell_user!(&mut writer, "Invalid name and/or password.\n\n{}", get_prompt!(world, PromptType::Login, PROMPT_LOGIN));
-                                        ClientState::EnteringName
-                                    }
-                                }
+                            Err(LoadError::InvalidName) => {
+                                tell_user!(&mut writer, "Name '{}' is reserved, please try another.\n\n{}", name, get_prompt!(world, PromptType::Login, PROMPT_LOGIN));
+                                ClientState::EnteringName
+                            },
+                            Err(LoadError::NoSuchSave) => {
+                                tell_user!(&mut writer, "{}", get_prompt!(world, PromptType::PasswordV, PROMPT_PASSWDV));
+                                ClientState::EnteringPasswordV { name, pw1: input }
                             },
+                            Err(e) => {
+                                log::warn!("Failed login attempt for '{}': {:?}", name, e);
+                                tell_user!(&mut writer, "Invalid name and/or password.\n\n{}", get_prompt!(world, PromptType::Login, PROMPT_LOGIN));
+                                ClientState::EnteringName
+                            }
+                        }
+                    },
 
-                            ClientState::EnteringPasswordV{ name, pw1 } => {
-                                if input == pw1 {
-                                    let mut player = Player::new(&name);
-                                    if player.set_passwd(input).await.is_ok() {
-                                        log::info!("New save being created for '{}'…", name);
-                                        player.set_access(Access::default());
-                                        player.location = world.read().await.root.room.clone();
-                                        let save_err = player.save().await;
-                                        if save_err.is_ok() {
-                                            let msg = {world.read().await.welcome_new.clone().unwrap_or_else(|| WELCOME_NEW.to_string())};
-                                            let p = Arc::new(RwLock::new(player));
-                                            let root_room = world.read().await.root.room.clone();
-                                            let _ = translocate(&world, None, root_room, p.clone()).await;
-                                            let (p_id, prompt, state) = {
-                                                let mut pl = p.write().await;
-                                                let p_id = pl.id().to_string();
-                                                let state = pl.erase_states(ClientState::Playing);
-                                                let prompt = pl.prompt().await;
-                                                log::info!("New player '{}' instantiated and translocated to '{}'.", p_id, &pl.location);
-                                                (p_id, prompt, state)
-                                            };
-                                            {
-                                                let mut w = world.write().await;
-                                                w.players_by_sockaddr.insert(addr.clone(), p.clone());
-                                                w.players.insert(p_id, p.clone());
-                                            }
-                                            tell_user!(&mut writer, "{}\n{}", msg, prompt);
-                                            state
-                                        } else {
-                                            // Some strange error happened with save...
-                                            // Notify user and "gracefully" disconnect them.
-                                            log::error!("Fatal error during save attempt of player '{}'! {:?}", name, save_err);
-                                            tell_user!(&mut writer, "\
-                                                    A server error occured during character creation!\n\
-                                                    \n\
-                                                    This could be due high server load or other reasons. \
-                                                    Try again a little later, but meanwhile please, notify \
-                                                    the owner of this MUD via email or other means!");
-                                            break;
-                                        }
-                                    } else {
-                                        tell_user!(&mut writer, "\
-                                                Given password is either too weak or a variant of it has been found in HIBP!\n\
-                                                Please, choose a different password: ");
-                                        ClientState::EnteringPassword1 { name }
+                    ClientState::EnteringPasswordV{ name, pw1 } => {
+                        if input == pw1 {
+                            let mut player = Player::new(&name);
+                            if player.set_passwd(input).await.is_ok() {
+                                log::info!("New save being created for '{}'…", name);
+                                player.set_access(Access::default());
+                                player.location = world.read().await.root.room.clone();
+                                let save_err = player.save().await;
+                                if save_err.is_ok() {
+                                    let msg = {world.read().await.welcome_new.clone().unwrap_or_else(|| WELCOME_NEW.to_string())};
+                                    let p = Arc::new(RwLock::new(player));
+                                    let root_room = world.read().await.root.room.clone();
+                                    let _ = translocate(&world, None, root_room, p.clone()).await;
+                                    let (p_id, prompt, state) = {
+                                        let mut pl = p.write().await;
+                                        let p_id = pl.id().to_string();
+                                        let state = pl.erase_states(ClientState::Playing);
+                                        let prompt = pl.prompt().await;
+                                        log::info!("New player '{}' instantiated and translocated to '{}'.", p_id, &pl.location);
+                                        (p_id, prompt, state)
+                                    };
+                                    {
+                                        let mut w = world.write().await;
+                                        w.players_by_sockaddr.insert(addr.clone(), p.clone());
+                                        w.players.insert(p_id, p.clone());
                                     }
+                                    tell_user!(&mut writer, "{}\n{}", msg, prompt);
+                                    state
                                 } else {
-                                    tell_user!(&mut writer, "Passwords do not match.\n\nPlease choose a password: ");
-                                    ClientState::EnteringPassword1 { name }
+                                    // Some strange error happened with save...
+                                    // Notify user and "gracefully" disconnect them.
+                                    log::error!("Fatal error during save attempt of player '{}'! {:?}", name, save_err);
+                                    tell_user!(&mut writer, "\
+                                            A server error occured during character creation!\n\
+                                            \n\
+                                            This could be due high server load or other reasons. \
+                                            Try again a little later, but meanwhile please, notify \
+                                            the owner of this MUD via email or other means!");
+                                    break;
                                 }
-                            },
+                            } else {
+                                tell_user!(&mut writer, "\
+                                        Given password is either too weak or a variant of it has been found in HIBP!\n\
+                                        Please, choose a different password: ");
+                                ClientState::EnteringPassword1 { name }
+                            }
+                        } else {
+                            tell_user!(&mut writer, "Passwords do not match.\n\nPlease choose a password: ");
+                            ClientState::EnteringPassword1 { name }
+                        }
+                    },
 
-                            // all the remaining [ClientState] (except Logout) pipe through CommandCtx:
-                            _ => {
-                                let prompt: String;
-                                let p = world.read().await.players_by_sockaddr.get(&addr).cloned();
-                                if let Some(p) = p {
+                    // all the remaining [ClientState] (except Logout) pipe through CommandCtx:
+                    _ => {
+                        let prompt: String;
+                        let p = world.read().await.players_by_sockaddr.get(&addr).cloned();
+                        if let Some(p) = p {
+                            let ctx = CommandCtx {
+                                player: p.clone(),
+                                state: p.read().await.state(),
+                                world: &world,
+                                tx: &tx,
+                                args: &input,
+                                writer: &mut writer,
+                                };
+                            state = cmd::parse_and_execute(ctx).await;
+                            prompt = p.read().await.prompt().await;
+                        } else {
+                            // player a goner?!
+                            abrupt_dc = true;
+                            state = ClientState::Logout;
+                            continue;
+                        }
+                        tell_user!(&mut writer, prompt);
+                        state
+                    },
+                };
+            },
+
+            // --- Second Branch: Receive broadcast messages from other clients/system itself ---
+            result = rx.recv() => {
+                // Majority of broadcast messages are treated as "Playing state only",
+                // which avoids e.g. the editor modes from being disturbed (too much).
+                match (&state, result) {
+                    (ClientState::Playing, Ok(msg)) => {
+                        if let Some(p) = world.read().await
+                            .players_by_sockaddr.get(&addr)
+                            .cloned()
+                        {
+                            if msg.is_recipient(&p, &world).await {
+                                let prompt = p.read().await.prompt().await;
+
+                                // Handle 'force' as a special case.
+                                if let Broadcast::Force { message, from_player, .. } = &msg {
+                                    match from_player {
+                                        ForceSource::Admin { anonymous: false, .. } => tell_user!(&mut writer, "\nAn admin has issued a command you feel compelled to comply with…"),
+                                        _ => tell_user!(&mut writer, "\nUnexpectedly you feel an urge to do something…"),
+                                    };
                                     let ctx = CommandCtx {
                                         player: p.clone(),
                                         state: p.read().await.state(),
                                         world: &world,
                                         tx: &tx,
-                                        args: &input,
+                                        args: &message,
                                         writer: &mut writer,
                                         };
                                     state = cmd::parse_and_execute(ctx).await;
-                                    prompt = p.read().await.prompt().await;
+                                    tell_user!(&mut writer, "{}", prompt);
                                 } else {
-                                    // player a goner?!
-                                    abrupt_dc = true;
-                                    state = ClientState::Logout;
-                                    continue;
+                                    // everything else but 'force' goes through Broadcast's message().
+                                    tell_user!(&mut writer, "\n{}\n{}", msg.message(), prompt);
                                 }
-                                tell_user!(&mut writer, prompt);
-                                state
-                            },
-                        };
-                    },
-
-                    // --- Second Branch: Receive broadcast messages from other clients/system itself ---
-                    result = rx.recv() => {
-                        // Majority of broadcast messages are treated as "Playing state only",
-                        // which avoids e.g. the editor modes from being disturbed (too much).
-                        match (&state, result) {
-                            (ClientState::Playing, Ok(msg)) => {
-                                if let Some(p) = world.read().await
-                                    .players_by_sockaddr.get(&addr)
-                                    .cloned()
-                                {
-                                    if msg.is_recipient(&p, &world).await {
-                                        let prompt = p.read().await.prompt().await;
-
-                                        // Handle 'force' as a special case.
-                                        if let Broadcast::Force { message, from_player, .. } = &msg {
-                                            match from_player {
-                                                ForceSource::Admin { anonymous: false, .. } => tell_user!(&mut writer, "\nAn admin has issued a command you feel compelled to comply with…"),
-                                                _ => tell_user!(&mut writer, "\nUnexpectedly you feel an urge to do something…"),
-                                            };
-                                            let ctx = CommandCtx {
-                                                player: p.clone(),
-                                                state: p.read().await.state(),
-                                                world: &world,
-                                                tx: &tx,
-                                                args: &message,
-                                                writer: &mut writer,
-                                                };
-                                            state = cmd::parse_and_execute(ctx).await;
-                                            tell_user!(&mut writer, "{}", prompt);
-                                        } else {
-                                            // everything else but 'force' goes through Broadcast's message().
-                                            tell_user!(&mut writer, "\n{}\n{}", msg.message(), prompt);
-                                        }
-                                    }
-                                    #[cfg(feature = "localtest")] {
-                                        log::debug!("Broadcast '{}' dispatched.", msg.message());
-                                    }
+                            }
+                            #[cfg(feature = "localtest")] {
+                                log::debug!("Broadcast '{}' dispatched.", msg.message());
+                            }
 
-                                    // Did we get told to shut down?
-                                    if let Broadcast::System(SystemBroadcastType::Shutdown{..}) = &msg {
-                                        tell_user!(&mut writer, "\n<c yellow>Server is shutting down. Logging you safely off…\n");
-                                        state = ClientState::Logout;
-                                    }
-                                }
+                            // Did we get told to shut down?
+                            if let Broadcast::System(SystemBroadcastType::Shutdown{..}) = &msg {
+                                tell_user!(&mut writer, "\n<c yellow>Server is shutting down. Logging you safely off…\n");
+                                state = ClientState::Logout;
                             }
-                            
-                            // we'll ignore all other states and/or 'failed' rx.recv()
-                            _ => ()
                         }
                     }
+                    
+                    // we'll ignore all other states and/or 'failed' rx.recv()
+                    _ => ()
                 }
             }
-        });
+        }
     }
+
+    world.write().await.session_fds.remove(&addr);
 }
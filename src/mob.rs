@@ -1,6 +1,10 @@
 pub mod gender;
 pub mod core;
 pub mod faction;
+pub mod template;
+pub mod behavior;
+pub mod instance;
+pub use instance::Mob;
 
 pub mod stat;
 pub use stat::CombatStat;
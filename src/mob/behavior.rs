@@ -0,0 +1,123 @@
+//! Mob behavior — composable [routines][Routine] on top of [MobFaction] defaults.
+//!
+//! Routines are listed per template in the `.mob` files, e.g.:
+//! ```json
+//! "faction": { "Beast": { "behavior": "Hostile" } },
+//! "routines": [ { "Wander": { "chance": 10 } }, { "Guard": { "exits": ["North"] } } ]
+//! ```
+use serde::{Deserialize, Serialize};
+
+use crate::{mob::{Mob, faction::{Behavior, MobFaction}}, traits::IdentityQuery, util::direction::Direction};
+
+const DEFAULT_WANDER_CHANCE: u8 = 5;// % per area tick.
+
+const fn default_wander_chance() -> u8 {DEFAULT_WANDER_CHANCE}
+
+/// A single behavior routine.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum Routine {
+    /// Roam around through non-locked exits, without ever leaving own area.
+    Wander {
+        /// Percentage chance to move per area tick.
+        #[serde(default = "default_wander_chance")]
+        chance: u8,
+    },
+    /// Block passage through `exits`.
+    Guard {
+        exits: Vec<Direction>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// React to anyone entering the room.
+    Hostile {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// Stay put, no matter what else says otherwise.
+    Stationary,
+}
+
+impl Mob {
+    /// Overall attitude — [Hostile][Routine::Hostile] routine overrides whatever
+    /// the [faction][MobFaction] would otherwise say.
+    pub fn behavior(&self) -> Behavior {
+        if self.core.routines.iter().any(|r| matches!(r, Routine::Hostile { .. })) {
+            return Behavior::Hostile;
+        }
+        self.core.faction.map(|f| f.default_behavior()).unwrap_or(Behavior::Neutral)
+    }
+
+    /// Chance (%) to wander off per area tick, if the mob wanders at all.
+    ///
+    /// Vendors never wander; they've got a shop to tend to.
+    pub fn wander_chance(&self) -> Option<u8> {
+        if matches!(self.core.faction, Some(MobFaction::Vendor))
+        || self.core.routines.iter().any(|r| matches!(r, Routine::Stationary)) {
+            return None;
+        }
+        self.core.routines.iter().find_map(|r| match r {
+            Routine::Wander { chance } => Some(*chance),
+            _ => None
+        })
+    }
+
+    /// See if the mob blocks passage towards `dir`.
+    ///
+    /// # Returns
+    /// Message to show for the one being blocked, if blocked.
+    pub fn blocks(&self, dir: &Direction) -> Option<String> {
+        self.core.routines.iter().find_map(|r| match r {
+            Routine::Guard { exits, message } if exits.contains(dir) =>
+                Some(message.clone().unwrap_or_else(|| format!("{} blocks your way {}.", self.title(), dir))),
+            _ => None
+        })
+    }
+
+    /// Reaction to `who` entering the room, if any.
+    pub fn react_to_arrival(&self, who: &str) -> Option<String> {
+        if self.behavior() != Behavior::Hostile {
+            return None;
+        }
+        let custom = self.core.routines.iter().find_map(|r| match r {
+            Routine::Hostile { message } => message.clone(),
+            _ => None
+        });
+        Some(custom.unwrap_or_else(|| format!("{} snarls at {}!", self.title(), who)))
+    }
+}
+
+#[cfg(test)]
+mod behavior_tests {
+    use crate::mob::core::MobCore;
+
+    use super::*;
+
+    fn mob(extra: &str) -> Mob {
+        Mob::spawn(&serde_json::from_str::<MobCore>(&format!(r#"{{
+            "name": "dog", "title": "a dog", "description": "Woof.",
+            "hp": {{"HP": {{"current": 5.0, "max": 5.0}}}},
+            "mp": {{"MP": {{"current": 0.0, "max": 0.0, "drain": 0.0}}}},
+            "invis": false{extra}
+        }}"#)).unwrap())
+    }
+
+    #[test]
+    fn composed_routines() {
+        let plain = mob("");
+        assert_eq!(Behavior::Neutral, plain.behavior());
+        assert!(plain.wander_chance().is_none());
+        assert!(plain.react_to_arrival("ani").is_none());
+
+        let guard = mob(r#", "faction": "Guard", "routines": [{"Wander": {}}, {"Guard": {"exits": ["North"]}}]"#);
+        assert_eq!(Some(DEFAULT_WANDER_CHANCE), guard.wander_chance());
+        assert!(guard.blocks(&Direction::North).is_some());
+        assert!(guard.blocks(&Direction::South).is_none());
+
+        let beast = mob(r#", "faction": {"Beast": {"behavior": "Hostile"}}, "routines": [{"Wander": {"chance": 50}}, "Stationary"]"#);
+        assert!(beast.wander_chance().is_none());
+        assert_eq!(Some("a dog snarls at ani!".into()), beast.react_to_arrival("ani"));
+
+        let vendor = mob(r#", "faction": "Vendor", "routines": [{"Wander": {}}]"#);
+        assert!(vendor.wander_chance().is_none());
+    }
+}
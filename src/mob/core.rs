@@ -1,9 +1,12 @@
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
-use crate::{mob::{CombatStat, stat::StatValue}, traits::{Description, IdentityQuery, mob::IsMob}};
+use crate::{mob::{CombatStat, behavior::Routine, faction::MobFaction, stat::StatValue}, traits::{Description, IdentityQuery, mob::IsMob, tickable::Tickable}};
 
 /// Core struct for mobs of all sorts.
-#[derive(Debug, Deserialize, Serialize)]
+///
+/// Doubles as a mob template, see [MobRegistry][crate::mob::template::MobRegistry].
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MobCore {
     name: String,
     title: String,
@@ -11,6 +14,10 @@ pub struct MobCore {
     hp: CombatStat,
     mp: CombatStat,
     invis: bool,
+    #[serde(default)]
+    pub faction: Option<MobFaction>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routines: Vec<Routine>,
 }
 
 impl Description for MobCore {
@@ -47,3 +54,11 @@ impl IsMob for MobCore {
         self.invis
     }
 }
+
+#[async_trait]
+impl Tickable for MobCore {
+    async fn tick(&mut self, uptime: u64) {
+        self.hp.tick(uptime).await;
+        self.mp.tick(uptime).await;
+    }
+}
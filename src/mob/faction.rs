@@ -1,7 +1,7 @@
 //! Mob "factions"…
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
 pub enum Behavior {
     Friendly,
     Neutral,
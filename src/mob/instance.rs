@@ -0,0 +1,76 @@
+//! Live mobs — [MobCore] templates brought to life.
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{mob::core::MobCore, string::uuid_id::AsUuidId, traits::{Description, IdentityQuery, tickable::Tickable}};
+
+/// A live mob instance somewhere in the world.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Mob {
+    id: String,
+    /// ID of the template the mob was spawned from.
+    template: String,
+    pub core: MobCore,
+}
+
+impl Mob {
+    /// Spawn a fresh [Mob] off of `template`.
+    pub fn spawn(template: &MobCore) -> Self {
+        Self {
+            id: template.id().uuided(),
+            template: template.id().into(),
+            core: template.clone(),
+        }
+    }
+
+    /// ID of the template the mob was spawned from.
+    pub fn template(&self) -> &str { &self.template }
+
+    /// Does `name` refer to this mob? Exact ID, template ID or a bit of the title will do.
+    pub fn answers_to(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+        self.id == name
+            || self.template == name
+            || self.title().to_lowercase().contains(&name)
+    }
+}
+
+impl IdentityQuery for Mob {
+    fn id(&self) -> &str { &self.id }
+    fn title(&self) -> &str { self.core.title() }
+}
+
+impl Description for Mob {
+    fn description(&self) -> &str { self.core.description() }
+}
+
+#[async_trait]
+impl Tickable for Mob {
+    async fn tick(&mut self, uptime: u64) {
+        self.core.tick(uptime).await;
+    }
+}
+
+#[cfg(test)]
+mod mob_tests {
+    use super::*;
+
+    #[test]
+    fn spawn_unique() {
+        let core: MobCore = serde_json::from_str(r#"{
+            "name": "rat",
+            "title": "a scruffy rat",
+            "description": "It squeaks.",
+            "hp": {"HP": {"current": 10.0, "max": 10.0}},
+            "mp": {"MP": {"current": 0.0, "max": 0.0, "drain": 0.0}},
+            "invis": false
+        }"#).unwrap();
+        let a = Mob::spawn(&core);
+        let b = Mob::spawn(&core);
+        assert_ne!(a.id(), b.id());
+        assert_eq!("rat", b.template());
+        assert!(a.answers_to("scruffy"));
+        assert!(a.answers_to(b.template()));
+        assert!(!a.answers_to(b.id()));
+    }
+}
@@ -0,0 +1,60 @@
+//! Mob templates, one `<id>.mob` file each under `data/mobs`.
+use std::{collections::HashMap, sync::Arc};
+
+use once_cell::sync::Lazy;
+use tokio::sync::RwLock;
+
+use crate::{DATA_PATH, mob::{Mob, core::MobCore}, traits::IdentityQuery};
+
+pub(crate) static MOB_PATH: Lazy<String> = Lazy::new(|| format!("{}/mobs", *DATA_PATH));
+pub(crate) static MOB_TEMPLATES: Lazy<Arc<RwLock<MobRegistry>>> = Lazy::new(|| Arc::new(RwLock::new(MobRegistry::default())));
+
+/// All the known [MobCore] templates.
+#[derive(Debug, Default)]
+pub struct MobRegistry {
+    templates: HashMap<String, MobCore>,
+}
+
+impl MobRegistry {
+    /// Load all templates from [MOB_PATH].
+    ///
+    /// Malformed files are skipped (with a warning).
+    pub async fn load() -> Result<Self, std::io::Error> {
+        tokio::fs::create_dir_all(MOB_PATH.as_str()).await?;
+        let mut templates = HashMap::new();
+        let mut dir = tokio::fs::read_dir(MOB_PATH.as_str()).await?;
+        while let Some(entry) = dir.next_entry().await? {
+            let path = entry.path();
+            if !path.is_file() || path.extension().and_then(|s| s.to_str()) != Some("mob") {
+                continue;
+            }
+            match serde_json::from_str::<MobCore>(&tokio::fs::read_to_string(&path).await?) {
+                Ok(core) => { templates.insert(core.id().to_string(), core); },
+                Err(e) => log::warn!("Mob template '{}' malformed: {e:?}", path.display()),
+            }
+        }
+        log::info!("{} mob template(s) loaded.", templates.len());
+        Ok(Self { templates })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&MobCore> {
+        self.templates.get(id)
+    }
+
+    /// Spawn a new [Mob] off of template `id`.
+    pub fn spawn(&self, id: &str) -> Option<Mob> {
+        self.get(id).map(Mob::spawn)
+    }
+
+    /// All templates, sorted by ID.
+    pub fn list(&self) -> Vec<&MobCore> {
+        let mut list = self.templates.values().collect::<Vec<_>>();
+        list.sort_by(|a, b| a.id().cmp(b.id()));
+        list
+    }
+
+    #[cfg(test)]
+    pub(crate) fn insert(&mut self, core: MobCore) {
+        self.templates.insert(core.id().to_string(), core);
+    }
+}
@@ -2,6 +2,10 @@
 pub mod pc;
 pub use pc::Player;
 pub use pc::LoadError;
+// Journal
+pub mod journal;
+// Knowledge
+pub mod knowledge;
 // Access
 pub mod access;
 pub(crate) use access::Access;
@@ -8,6 +8,8 @@ pub enum Access {
         event_host: bool
     },
     Builder,
+    /// A builder who also gets to publish drafts.
+    BuilderLead,
     Admin,
 }
 
@@ -16,6 +18,7 @@ impl Access {
     pub fn is_builder(&self) -> bool {
         match self {
             Self::Admin   |
+            Self::BuilderLead |
             Self::Builder => true,
             Self::Player {builder, ..} => *builder,
             _ => false
@@ -36,6 +39,11 @@ impl Access {
         Self::Player { builder: false, event_host: false }
     }
 
+    /// Check if may publish builders' drafts.
+    pub fn is_builder_lead(&self) -> bool {
+        matches!(self, Self::Admin | Self::BuilderLead)
+    }
+
     /// Check if has full admin rights.
     pub fn is_admin(&self) -> bool {
         match self {
@@ -54,6 +62,60 @@ macro_rules! validate_builder {
     };
 }
 
+#[macro_export]
+macro_rules! validate_builder_lead {
+    ($ctx:expr) => {
+        if !$ctx.player.read().await.access.is_builder_lead() {
+            return crate::tell_user_unk!($ctx.writer);
+        }
+    };
+}
+
+/// See that the builder may build within the given area.
+/// Denials are logged.
+#[macro_export]
+macro_rules! validate_area_builder {
+    ($ctx:expr, $area_id:expr) => {{
+        let area_id: &str = &$area_id;
+        let p = $ctx.player.read().await;
+        if !$ctx.world.read().await.may_build(&p, area_id).await {
+            log::warn!("Builder '{}' denied building within area '{}'.", crate::traits::IdentityQuery::id(&*p), area_id);
+            drop(p);
+            return crate::tell_user!($ctx.writer, "You're not authorized to build within area '{}'.\n", area_id);
+        }
+    }};
+}
+
+/// See that the builder may configure the given area (or create it, if new).
+/// Denials are logged.
+#[macro_export]
+macro_rules! validate_area_owner {
+    ($ctx:expr, $area_id:expr) => {{
+        let area_id: &str = &$area_id;
+        let p = $ctx.player.read().await;
+        if !$ctx.world.read().await.may_configure(&p, area_id).await {
+            log::warn!("Builder '{}' denied configuring area '{}'.", crate::traits::IdentityQuery::id(&*p), area_id);
+            drop(p);
+            return crate::tell_user!($ctx.writer, "You're not authorized to configure area '{}'.\n", area_id);
+        }
+    }};
+}
+
+/// See that the builder may (over)write the given item blueprint.
+/// Denials are logged.
+#[macro_export]
+macro_rules! validate_blueprint_author {
+    ($ctx:expr, $bp_id:expr) => {{
+        let bp_id: &str = &$bp_id;
+        let p = $ctx.player.read().await;
+        if !crate::item::blueprint::BLUEPRINTS.read().await.may_edit(&p, bp_id) {
+            log::warn!("Builder '{}' denied editing blueprint '{}'.", crate::traits::IdentityQuery::id(&*p), bp_id);
+            drop(p);
+            return crate::tell_user!($ctx.writer, "You're not authorized to change blueprint '{}'.\n", bp_id);
+        }
+    }};
+}
+
 #[macro_export]
 macro_rules! validate_admin {
     ($ctx:expr) => {
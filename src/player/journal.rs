@@ -0,0 +1,228 @@
+//! Write-ahead journal for [Player] state between saves.
+//!
+//! Significant mutations are appended to `save/<name>.journal`, one JSON
+//! record per line. At load the journal is replayed on top of the save, and
+//! it is wiped after each successful save.
+//!
+//! Ownership transfers ride along with [Gained][JournalEntry::Gained] —
+//! the item is recorded as-is, new owner and all.
+//!
+//! Nothing touches the disk from the caller's end: records are handed over
+//! to a [writer task][start] which batches them up. Until the writer is
+//! started (as is the case in tests) journaling is a no-op.
+use std::{collections::HashMap, sync::OnceLock};
+
+use serde::{Deserialize, Serialize};
+use tokio::{io::AsyncWriteExt, sync::{mpsc, oneshot}};
+
+use crate::{item::{Item, inventory::Storage}, player::{Player, knowledge::Knowledge}, traits::IdentityQuery, util::timestamp::unix_now};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum JournalEntry {
+    /// Moved to another room.
+    Moved(String),
+    /// Item entered inventory.
+    Gained(Item),
+    /// Item (by ID) left inventory.
+    Lost(String),
+    /// Learned of something (by ID).
+    Learned(String, Knowledge),
+    /// Forgot something (by ID).
+    Forgot(String),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct JournalRecord {
+    at: u64,
+    entry: JournalEntry,
+}
+
+enum JournalOp {
+    /// Append a (serialized) record into the journal of `slug`.
+    Append(String, String),
+    /// Wipe the journal of `slug`.
+    Truncate(String),
+    /// Write out whatever is pending, then ping back.
+    Flush(oneshot::Sender<()>),
+}
+
+struct JournalSink {
+    dir: String,
+    tx: mpsc::UnboundedSender<JournalOp>,
+}
+
+static SINK: OnceLock<JournalSink> = OnceLock::new();
+
+fn journal_path(dir: &str, slug: &str) -> String {
+    format!("{}/{}.journal", dir, slug)
+}
+
+/// Start the journal writer, with journals living in `dir`.
+///
+/// Only the first call counts; later ones are ignored.
+pub(crate) fn start(dir: &str) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    if SINK.set(JournalSink { dir: dir.into(), tx }).is_ok() {
+        tokio::spawn(run_writer(dir.into(), rx));
+    }
+}
+
+fn send(op: JournalOp) {
+    if let Some(sink) = SINK.get() && sink.tx.send(op).is_err() {
+        log::error!("Journal writer is gone?!");
+    }
+}
+
+/// Append `entry` into the journal of `slug`.
+pub(crate) fn append(slug: &str, entry: JournalEntry) -> Result<(), serde_json::Error> {
+    let mut line = serde_json::to_string(&JournalRecord { at: unix_now(), entry })?;
+    line.push('\n');
+    send(JournalOp::Append(slug.into(), line));
+    Ok(())
+}
+
+/// Wipe the journal of `slug`.
+///
+/// Whatever was appended before this is moot by now (the save has it), so
+/// it won't be written out at all.
+pub(crate) fn truncate(slug: &str) {
+    send(JournalOp::Truncate(slug.into()));
+}
+
+/// Wait till everything journaled so far is on disk.
+pub(crate) async fn flush() {
+    let (tx, rx) = oneshot::channel();
+    send(JournalOp::Flush(tx));
+    if SINK.get().is_some() {
+        let _ = rx.await;
+    }
+}
+
+/// The writer itself — drains `rx` in batches, one write per journal per batch.
+async fn run_writer(dir: String, mut rx: mpsc::UnboundedReceiver<JournalOp>) {
+    let mut batch = vec![];
+    while rx.recv_many(&mut batch, 256).await > 0 {
+        let mut pending: HashMap<String, String> = HashMap::new();
+        let mut flushed = vec![];
+        for op in batch.drain(..) {
+            match op {
+                JournalOp::Append(slug, line) => pending.entry(slug).or_default().push_str(&line),
+                JournalOp::Truncate(slug) => {
+                    pending.remove(&slug);
+                    match tokio::fs::remove_file(journal_path(&dir, &slug)).await {
+                        Err(e) if e.kind() != std::io::ErrorKind::NotFound => log::error!("Could not wipe journal of '{}': {e:?}", slug),
+                        _ => ()
+                    }
+                },
+                JournalOp::Flush(tx) => flushed.push(tx),
+            }
+        }
+        for (slug, lines) in pending {
+            if let Err(e) = write_out(&journal_path(&dir, &slug), &lines).await {
+                log::error!("Could not journal for '{}': {e:?}", slug);
+            }
+        }
+        for tx in flushed {
+            let _ = tx.send(());
+        }
+    }
+}
+
+async fn write_out(path: &str, lines: &str) -> Result<(), std::io::Error> {
+    tokio::fs::OpenOptions::new().create(true).append(true)
+        .open(path).await?
+        .write_all(lines.as_bytes()).await
+}
+
+/// Read the journal of `slug`, skipping over any torn/garbled lines.
+async fn read(slug: &str) -> Vec<JournalEntry> {
+    let Some(sink) = SINK.get() else { return vec![] };
+    flush().await;
+    let Ok(content) = tokio::fs::read_to_string(journal_path(&sink.dir, slug)).await else { return vec![] };
+    content.lines()
+        .filter_map(|line| match serde_json::from_str::<JournalRecord>(line) {
+            Ok(r) => Some(r.entry),
+            Err(e) => {
+                log::warn!("Journal '{}': skipping garbled record: {e:?}", slug);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Apply a single `entry` onto `player`.
+fn apply(player: &mut Player, entry: JournalEntry) {
+    match entry {
+        JournalEntry::Moved(to) => player.location = to,
+        JournalEntry::Gained(item) => {
+            if !player.inventory.contains(item.id())
+            && let Err(e) = player.inventory.try_insert(item) {
+                log::warn!("Journal '{}': could not re-gain item: {e}", player.id());
+            }
+        },
+        JournalEntry::Lost(id) => { let _ = player.inventory.take_out(&id); },
+        JournalEntry::Learned(id, knowledge) => { player.known_secrets.learn(&id, knowledge); },
+        JournalEntry::Forgot(id) => { player.known_secrets.forget(&id); },
+    }
+}
+
+/// Replay the journal of `slug` onto `player`.
+///
+/// # Returns
+/// Number of entries replayed.
+pub(crate) async fn replay(slug: &str, player: &mut Player) -> usize {
+    let entries = read(slug).await;
+    let num = entries.len();
+    for entry in entries {
+        apply(player, entry);
+    }
+    if num > 0 {
+        log::info!("Replayed {} journal entr{} for '{}'.", num, if num == 1 {"y"} else {"ies"}, player.id());
+    }
+    num
+}
+
+#[cfg(test)]
+mod journal_tests {
+    use crate::item::item::ItemType;
+
+    use super::*;
+
+    #[test]
+    fn apply_entries() {
+        let mut p = Player::new("journaler");
+        let item = Item::new(ItemType::Weapon);
+        let id = item.id().to_string();
+        apply(&mut p, JournalEntry::Moved("clearing".into()));
+        apply(&mut p, JournalEntry::Gained(item.clone()));
+        apply(&mut p, JournalEntry::Gained(item));
+        assert_eq!("clearing", p.location);
+        assert_eq!(1, p.inventory.items().len());
+        apply(&mut p, JournalEntry::Lost(id.clone()));
+        assert!(!p.inventory.contains(&id));
+    }
+
+    #[tokio::test]
+    async fn writer_batches_and_truncates() {
+        let dir = std::env::temp_dir().join(format!("rustrom-journal-{}", std::process::id()));
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let dir = dir.to_string_lossy().to_string();
+        let (tx, rx) = mpsc::unbounded_channel();
+        let writer = tokio::spawn(run_writer(dir.clone(), rx));
+        let record = |to: &str| format!("{}\n", serde_json::to_string(&JournalRecord { at: 0, entry: JournalEntry::Moved(to.into()) }).unwrap());
+
+        tx.send(JournalOp::Append("bob".into(), record("a"))).unwrap();
+        tx.send(JournalOp::Truncate("bob".into())).unwrap();
+        tx.send(JournalOp::Append("bob".into(), record("b"))).unwrap();
+        tx.send(JournalOp::Append("bob".into(), record("c"))).unwrap();
+        let (ftx, frx) = oneshot::channel();
+        tx.send(JournalOp::Flush(ftx)).unwrap();
+        frx.await.unwrap();
+
+        let content = tokio::fs::read_to_string(journal_path(&dir, "bob")).await.unwrap();
+        assert_eq!(record("b") + &record("c"), content);
+        drop(tx);
+        writer.await.unwrap();
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+}
@@ -0,0 +1,85 @@
+//! Per-[Player][crate::player::Player] knowledge — secrets learned, lore discovered, etc.
+//!
+//! Entries are keyed by a free-form ID, by convention prefixed with what kind
+//! of thing is known, e.g. `exit:<room>:<dir>` or `help:<topic>`.
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::timestamp::unix_now;
+
+/// A single piece of knowledge.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Knowledge {
+    /// When learned, seconds since UNIX epoch.
+    pub learned_at: u64,
+    /// How/where/from whom learned.
+    pub source: String,
+}
+
+impl Knowledge {
+    pub fn new(source: &str) -> Self {
+        Self { learned_at: unix_now(), source: source.into() }
+    }
+}
+
+/// All that's known, by ID.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct KnowledgeBase(HashMap<String, Knowledge>);
+
+impl KnowledgeBase {
+    /// Is `id` known?
+    pub fn knows(&self, id: &str) -> bool {
+        self.0.contains_key(id)
+    }
+
+    /// Learn `id`.
+    ///
+    /// # Returns
+    /// `true` if it was something new.
+    pub fn learn(&mut self, id: &str, knowledge: Knowledge) -> bool {
+        if self.knows(id) {
+            return false;
+        }
+        self.0.insert(id.into(), knowledge);
+        true
+    }
+
+    /// Forget `id`.
+    ///
+    /// # Returns
+    /// `true` if there was something to forget.
+    pub fn forget(&mut self, id: &str) -> bool {
+        self.0.remove(id).is_some()
+    }
+
+    /// Everything known, sorted by ID.
+    pub fn list(&self) -> Vec<(&String, &Knowledge)> {
+        let mut list = self.0.iter().collect::<Vec<_>>();
+        list.sort_by_key(|(id, _)| *id);
+        list
+    }
+}
+
+#[cfg(test)]
+mod knowledge_tests {
+    use super::*;
+
+    #[test]
+    fn learn_and_forget() {
+        let mut kb: KnowledgeBase = serde_json::from_str(r#"{
+            "exit:pantry:down": {"learned_at": 1000, "source": "search"},
+            "help:runes": {"learned_at": 1234, "source": "an old tome"}
+        }"#).unwrap();
+        assert_eq!(1000, kb.0["exit:pantry:down"].learned_at);
+        assert_eq!("an old tome", kb.0["help:runes"].source);
+        assert!(!kb.learn("help:runes", Knowledge::new("again")));
+        assert!(kb.learn("help:stars", Knowledge::new("stargazing")));
+        assert_eq!(vec!["exit:pantry:down", "help:runes", "help:stars"], kb.list().iter().map(|(id, _)| id.as_str()).collect::<Vec<_>>());
+        assert!(kb.forget("help:runes"));
+        assert!(!kb.forget("help:runes"));
+        let json = serde_json::to_string(&kb).unwrap();
+        assert!(serde_json::from_str::<KnowledgeBase>(&json).unwrap().knows("help:stars"));
+    }
+}
@@ -6,10 +6,10 @@ use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
-use crate::{DATA_PATH, cmd::{CommandCtx, hedit::HeditState, redit::ReditState}, item::inventory::{Container, ContainerType}, mob::{CombatStat, gender::Gender, stat::{StatType, StatValue}}, player::Access, string::{WordSet, styling::dirty_mark}, traits::{Description, IdentityQuery, mob::IsMob, save::{DoesSave, SaveError}}, util::{ClientState, badname::filter_bad_name, clientstate::EditorMode, comm::Channel, password::{PasswordError, validate_passwd}}, world::room::Room};
+use crate::{DATA_PATH, cmd::{CommandCtx, aedit::AeditState, hedit::HeditState, iedit::IeditState, redit::ReditState}, item::inventory::{Container, ContainerType, Storage}, mob::{CombatStat, gender::Gender, stat::{StatType, StatValue}}, player::{Access, journal::{self, JournalEntry}, knowledge::{Knowledge, KnowledgeBase}}, string::{WordSet, styling::dirty_mark}, traits::{Description, IdentityQuery, mob::IsMob, save::{DoesSave, SaveError}}, util::{ClientState, badname::filter_bad_name, clientstate::EditorMode, comm::Channel, password::{PasswordError, validate_passwd}}, world::room::Room};
 use crate::string::Sluggable;
 
-static SAVE_PATH: Lazy<Arc<String>> = Lazy::new(|| Arc::new(format!("{}/save", *DATA_PATH)));
+pub(crate) static SAVE_PATH: Lazy<Arc<String>> = Lazy::new(|| Arc::new(format!("{}/save", *DATA_PATH)));
 pub const MAX_ITEMS_PLAYER_INVENTORY: usize = 64;
 
 #[derive(Debug)]
@@ -51,11 +51,15 @@ static DUMMY_SAVE: Lazy<Arc<Player>> = Lazy::new(|| Arc::new(Player {
         state_stack: vec![ClientState::Logout],
         hedit: None,
         redit: None,
+        aedit: None,
+        iedit: None,
         listening_to: HashSet::new(),
         inventory: Container::from(ContainerType::PlayerInventory),
         act_count: 0,
         room: Weak::new(),
         invis: false,
+        automap: false,
+        known_secrets: KnowledgeBase::default(),
     }));
 
 /// Player data lives here!
@@ -75,9 +79,15 @@ pub struct Player {
     #[serde(skip, default)] state_stack: Vec<ClientState>,
     #[serde(default)] pub hedit: Option<HeditState>,
     #[serde(default)] pub redit: Option<ReditState>,
+    #[serde(default)] pub aedit: Option<AeditState>,
+    #[serde(default)] pub iedit: Option<IeditState>,
     #[serde(default)] pub listening_to: HashSet<Channel>,
     #[serde(default)] pub inventory: Container,
     #[serde(default)] pub invis: bool,
+    /// Show the minimap along with 'look'?
+    #[serde(default)] pub automap: bool,
+    /// Secrets learned, lore discovered, etc.
+    #[serde(default)] pub known_secrets: KnowledgeBase,
 }
 
 impl Player {
@@ -98,11 +108,15 @@ impl Player {
             state_stack: vec![ClientState::EnteringName],
             hedit: None,
             redit: None,
+            aedit: None,
+            iedit: None,
             listening_to: Channel::default_listens(),
             inventory: Container::from(ContainerType::PlayerInventory),
             act_count: 0,
             room: Weak::new(),
             invis: false,
+            automap: false,
+            known_secrets: KnowledgeBase::default(),
         }
     }
 
@@ -173,15 +187,17 @@ impl Player {
                 return Err(LoadError::NoSuchSave);
             }
         };
-        let save: Player = serde_json::from_str(&save)?;
+        let mut save: Player = serde_json::from_str(&save)?;
         #[cfg(all(debug_assertions, feature = "localtest"))]
         {   log::debug!("ADDR: {}", _addr.to_string());
             if _addr.to_string().split(":").nth(0).eq(&Some("127.0.0.1")) {
                 log::warn!("Local test - bypassing password verification.");
+                save.replay_journal().await;
                 return Ok(save);
             }
         }
         if save.verify_passwd(plaintext_passwd) {
+            save.replay_journal().await;
             Ok(save)
         } else {
             log::warn!("Password failure for user '{}'", name);
@@ -189,6 +205,18 @@ impl Player {
         }
     }
 
+    /// Load a save *without* password checks.
+    /// 
+    /// Reserved for picking sessions back up after a copyover — the
+    /// connection itself was authenticated by our previous incarnation.
+    pub(crate) async fn resume(name: &str) -> Result<Player, LoadError> {
+        let filename = format!("{}/{}.save", *SAVE_PATH, name.slugify());
+        let content = tokio::fs::read_to_string(&filename).await.map_err(|_| LoadError::NoSuchSave)?;
+        let mut save: Player = serde_json::from_str(&content)?;
+        save.replay_journal().await;
+        Ok(save)
+    }
+
     /// Check if loading is possible.
     /// 
     /// # Arguments
@@ -293,6 +321,58 @@ impl Player {
         self.add_act_count(1);
     }
 
+    /// Carrying anything that sheds light?
+    pub fn has_light(&self) -> bool {
+        self.inventory.items().values().any(|i| i.is_light())
+    }
+
+    /// Is `id` known?
+    pub fn knows(&self, id: &str) -> bool {
+        self.known_secrets.knows(id)
+    }
+
+    /// Learn of `id` from `source`.
+    ///
+    /// # Returns
+    /// `true` if it was something new.
+    pub fn learn(&mut self, id: &str, source: &str) -> bool {
+        let knowledge = Knowledge::new(source);
+        if !self.known_secrets.learn(id, knowledge.clone()) {
+            return false;
+        }
+        self.journal(JournalEntry::Learned(id.into(), knowledge));
+        self.inc_act_count();
+        true
+    }
+
+    /// Forget all about `id`.
+    ///
+    /// # Returns
+    /// `true` if there was something to forget.
+    pub fn forget(&mut self, id: &str) -> bool {
+        if !self.known_secrets.forget(id) {
+            return false;
+        }
+        self.journal(JournalEntry::Forgot(id.into()));
+        self.inc_act_count();
+        true
+    }
+
+    /// Journal a significant mutation, to be replayed at load if the
+    /// server goes down before the next save.
+    pub fn journal(&self, entry: JournalEntry) {
+        if let Err(e) = journal::append(&self.name.slugify(), entry) {
+            log::error!("Could not journal for '{}': {e:?}", self.name);
+        }
+    }
+
+    /// Replay whatever the journal has on top of the loaded save.
+    async fn replay_journal(&mut self) {
+        let num = journal::replay(&self.name.slugify(), self).await;
+        // Not on disk in the save proper yet, so treat as 'activity'.
+        self.add_act_count(num as u32);
+    }
+
     /// Blocking save of [Player].
     fn save_blocking(&mut self) -> Result<(), SaveError> {
         let filename = format!("{}/{}.save", *SAVE_PATH, self.name.slugify());
@@ -300,6 +380,9 @@ impl Player {
         let file = std::fs::File::create(path)?;
         let _ = serde_json::to_writer_pretty(file, &self)?;
         log::info!("Saved '{}'.", filename);
+
+        // Journal is now redundant.
+        journal::truncate(&self.name.slugify());
         
         // Reset act count.
         self.act_count = 0;
@@ -332,6 +415,14 @@ impl IsMob for Player {
                     let r = self.redit.as_ref().unwrap();
                     format!("ROOM(<c yellow>{}{}</c>)", r.entry.id(), dirty_mark(r.dirty))
                 },
+                EditorMode::Area => {
+                    let a = self.aedit.as_ref().unwrap();
+                    format!("AREA(<c yellow>{}{}</c>)", a.entry.id(), dirty_mark(a.dirty))
+                },
+                EditorMode::Item => {
+                    let i = self.iedit.as_ref().unwrap();
+                    format!("ITEM(<c yellow>{}{}</c>)", i.entry.id(), dirty_mark(i.dirty))
+                },
             }),
             _ => "#> ".into()
         }
@@ -20,3 +20,4 @@ pub mod robust_parse;
 pub mod rx;
 pub mod ansi;
 pub mod uuid_id;
+pub mod template;
@@ -0,0 +1,231 @@
+//! Text templates — placeholders and conditional sections within e.g. room
+//! descriptions and help texts, evaluated per viewer.
+//!
+//! - `{name}`, `{world}`, `{season}`, `{weather}`, `{month}`, `{hour}` — substitutions.
+//! - `{if COND}…{else}…{end}` — conditional sections; these nest.
+//! - `{{` — a literal `{`.
+//!
+//! `COND` is one or more of `day`, `night`, a season, a weather, `builder`,
+//! `admin` or `knows:SECRET-ID`, separated by `|` (any of them will do).
+//! A leading `!` negates a condition.
+//!
+//! Anything else within braces is left as-is, and so are `<c …>` color tags.
+use std::fmt::Display;
+
+use crate::{player::Player, traits::IdentityQuery, world::{World, calendar::{GameTime, Season}, weather::Weather}};
+
+#[derive(Debug, PartialEq)]
+pub enum TemplateError {
+    UnknownCondition(String),
+    /// `{else}` or `{end}` without an `{if}`.
+    Stray(String),
+    /// `{if}`s left without an `{end}`.
+    Unclosed(usize),
+}
+
+impl std::error::Error for TemplateError {}
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownCondition(c) => write!(f, "No such condition as '{}'", c),
+            Self::Stray(t) => write!(f, "'{{{}}}' without a matching '{{if …}}'", t),
+            Self::Unclosed(n) => write!(f, "{} '{{if …}}' left without an '{{end}}'", n),
+        }
+    }
+}
+
+/// Whatever the template gets evaluated against.
+pub struct TemplateCtx<'a> {
+    pub player: &'a Player,
+    pub world_title: &'a str,
+    pub time: GameTime,
+    pub weather: Weather,
+}
+
+impl<'a> TemplateCtx<'a> {
+    /// Context for `player`, with the weather at wherever they stand.
+    pub async fn new(world: &'a World, player: &'a Player) -> Self {
+        Self {
+            player,
+            world_title: world.title(),
+            time: world.time(),
+            weather: world.weather_at(&player.location).await,
+        }
+    }
+
+    fn var(&self, name: &str) -> Option<String> {
+        Some(match name {
+            "name" => self.player.id().into(),
+            "world" => self.world_title.into(),
+            "season" => self.time.season().to_string(),
+            "weather" => self.weather.to_string(),
+            "month" => self.time.month_name().into(),
+            "hour" => format!("{:02}", self.time.hour),
+            _ => return None
+        })
+    }
+
+    /// Does `cond` hold? Unknown conditions never do.
+    fn holds(&self, cond: &str) -> bool {
+        cond.split('|').map(str::trim).any(|term| {
+            let (negate, term) = match term.strip_prefix('!') {
+                Some(t) => (true, t.trim()),
+                None => (false, term)
+            };
+            let held = match term {
+                "day" => self.time.is_daytime(),
+                "night" => !self.time.is_daytime(),
+                "builder" => self.player.access.is_builder(),
+                "admin" => self.player.access.is_admin(),
+                t => match t.strip_prefix("knows:") {
+                    Some(id) => self.player.knows(id.trim()),
+                    None if SEASONS.iter().any(|s| s.to_string() == t) => self.time.season().to_string() == t,
+                    None if WEATHERS.iter().any(|w| w.to_string() == t) => self.weather.to_string() == t,
+                    None => return false
+                }
+            };
+            held != negate
+        })
+    }
+}
+
+const SEASONS: [Season; 4] = [Season::Winter, Season::Spring, Season::Summer, Season::Autumn];
+const WEATHERS: [Weather; 4] = [Weather::Clear, Weather::Rain, Weather::Storm, Weather::Snow];
+
+fn known_condition(cond: &str) -> bool {
+    cond.split('|').map(|t| t.trim().trim_start_matches('!').trim()).all(|t|
+        matches!(t, "day"|"night"|"builder"|"admin")
+        || t.strip_prefix("knows:").is_some_and(|id| !id.trim().is_empty())
+        || SEASONS.iter().any(|s| s.to_string() == t)
+        || WEATHERS.iter().any(|w| w.to_string() == t))
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    /// `{…}`, braces stripped.
+    Tag(&'a str),
+}
+
+/// Chop `text` into plain text and `{tags}`.
+///
+/// A tag doesn't span lines or contain other braces — such a `{` is just text.
+fn tokens(text: &str) -> Vec<Token<'_>> {
+    let mut out = vec![];
+    let mut rest = text;
+    while let Some(at) = rest.find('{') {
+        if rest[at..].starts_with("{{") {
+            out.push(Token::Text(&rest[..at + 1]));
+            rest = &rest[at + 2..];
+            continue;
+        }
+        let tail = &rest[at + 1..];
+        match tail.find(['}', '{', '\n']) {
+            Some(end) if tail[end..].starts_with('}') => {
+                out.push(Token::Text(&rest[..at]));
+                out.push(Token::Tag(&tail[..end]));
+                rest = &tail[end + 1..];
+            },
+            _ => {
+                out.push(Token::Text(&rest[..at + 1]));
+                rest = tail;
+            }
+        }
+    }
+    out.push(Token::Text(rest));
+    out
+}
+
+/// See that `text` is a well-formed template.
+pub fn check(text: &str) -> Result<(), TemplateError> {
+    let mut depth = 0;
+    for token in tokens(text) {
+        let Token::Tag(tag) = token else { continue };
+        match tag.trim() {
+            "else" if depth == 0 => return Err(TemplateError::Stray("else".into())),
+            "end" if depth == 0 => return Err(TemplateError::Stray("end".into())),
+            "end" => depth -= 1,
+            t => if let Some(cond) = t.strip_prefix("if ") {
+                if !known_condition(cond) {
+                    return Err(TemplateError::UnknownCondition(cond.trim().into()));
+                }
+                depth += 1;
+            }
+        }
+    }
+    match depth {
+        0 => Ok(()),
+        n => Err(TemplateError::Unclosed(n))
+    }
+}
+
+/// Render `text` for whoever `ctx` is about.
+///
+/// Malformed bits don't stop the show: stray `{else}`/`{end}` stay as-is and
+/// unclosed `{if}`s run till the end of text.
+pub fn render(text: &str, ctx: &TemplateCtx<'_>) -> String {
+    // (condition held, in else-branch)
+    let mut stack: Vec<(bool, bool)> = vec![];
+    let active = |stack: &Vec<(bool, bool)>| stack.iter().all(|(held, other)| held != other);
+    let mut out = String::with_capacity(text.len());
+    for token in tokens(text) {
+        match token {
+            Token::Text(t) => if active(&stack) { out.push_str(t) },
+            Token::Tag(tag) => match tag.trim() {
+                "else" if !stack.is_empty() => { stack.last_mut().unwrap().1 = true; },
+                "end" if !stack.is_empty() => { stack.pop(); },
+                t => if let Some(cond) = t.strip_prefix("if ") {
+                    stack.push((ctx.holds(cond), false));
+                } else if active(&stack) {
+                    match ctx.var(t) {
+                        Some(v) => out.push_str(&v),
+                        None => out.push_str(&format!("{{{}}}", tag))
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod template_tests {
+    use crate::{player::knowledge::Knowledge, world::calendar::GameTime};
+
+    use super::*;
+
+    fn ctx<'a>(player: &'a Player, hour: u64, weather: Weather) -> TemplateCtx<'a> {
+        TemplateCtx { player, world_title: "Testland", time: GameTime { year: 1, month: 6, day: 1, hour }, weather }
+    }
+
+    #[test]
+    fn substitutions() {
+        let p = Player::new("bob");
+        let c = ctx(&p, 12, Weather::Rain);
+        assert_eq!("Hi bob, welcome to <c yellow>Testland</c>! It's rain in summer. {nope}",
+            render("Hi {name}, welcome to <c yellow>{world}</c>! It's {weather} in {season}. {nope}", &c));
+        assert_eq!("{name} is literal", render("{{name} is literal", &c));
+        assert_eq!("a { b", render("a { b", &c));
+    }
+
+    #[test]
+    fn conditionals() {
+        let mut p = Player::new("bob");
+        let text = "The square.{if day} Busy.{else} Quiet.{if rain|storm} Wet, too.{end}{end}{if knows:x} A lever.{end}";
+        assert!(check(text).is_ok());
+        assert_eq!("The square. Busy.", render(text, &ctx(&p, 12, Weather::Rain)));
+        assert_eq!("The square. Quiet. Wet, too.", render(text, &ctx(&p, 23, Weather::Storm)));
+        assert_eq!("The square. Quiet.", render(text, &ctx(&p, 2, Weather::Clear)));
+        p.known_secrets.learn("x", Knowledge::new("test"));
+        assert_eq!("The square. Quiet. A lever.", render(text, &ctx(&p, 2, Weather::Clear)));
+        assert_eq!("Not winter.", render("{if !winter}Not winter.{end}", &ctx(&p, 2, Weather::Clear)));
+    }
+
+    #[test]
+    fn malformed() {
+        let p = Player::new("bob");
+        assert_eq!(Err(TemplateError::UnknownCondition("noon".into())), check("{if noon}x{end}"));
+        assert_eq!(Err(TemplateError::Stray("end".into())), check("x{end}"));
+        assert_eq!(Err(TemplateError::Unclosed(1)), check("{if day}x"));
+        assert_eq!("x{end}", render("x{end}", &ctx(&p, 12, Weather::Clear)));
+    }
+}
@@ -21,6 +21,11 @@ pub use ed::Editor;
 pub mod boolean;
 pub use boolean::AsSetting;
 
+pub mod timestamp;
+pub mod snapshot;
+pub mod copyover;
+pub mod history;
+
 #[macro_export]
 macro_rules! get_prompt {
     ($w:ident, $pt:expr, $default:tt) => {
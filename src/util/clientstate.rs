@@ -1,10 +1,14 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum EditorMode {
     Room,
     Help,
+    Area,
+    Item,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum ClientState {
     EnteringName,
     EnteringPassword1 { name: String },
@@ -143,6 +143,12 @@ pub enum Broadcast {
         message: String,
         from_player: String,
     },
+    /// Something happening area-wide (e.g. due a reset), or in a single room within.
+    Area {
+        area_id: String,
+        room_id: Option<String>,
+        message: String,
+    },
     /// System-only variants:
     System(SystemBroadcastType),
 }
@@ -150,12 +156,14 @@ pub enum Broadcast {
 #[derive(Debug, Clone)]
 pub enum SystemBroadcastType {
     Shutdown { message: String, seconds: u64 },
+    Copyover { message: String },
 }
 
 impl SystemBroadcastType {
     pub(crate) fn message(&self) -> String {
         match self {
-            Self::Shutdown { message, .. } => message.clone(),
+            Self::Shutdown { message, .. } |
+            Self::Copyover { message } => message.clone(),
         }
     }
 }
@@ -203,6 +211,7 @@ impl MessagePayload for Broadcast {
             Self::Say { from_player, .. }    => from_player.clone(),
             Self::Tell { from_player, ..}  => from_player.into(),
             Self::Force { from_player, .. } => from_player.id().to_string(),
+            Self::Area { .. }  |
             Self::System(_) => "system".into(),
         }
     }
@@ -219,6 +228,7 @@ impl MessagePayload for Broadcast {
                     Channel::Qa => format!("[Q&A]({}): {}", from_player, message),
                 }
             }
+            Self::Area { message, .. } |
             Self::Force { message, .. }|
             Self::Say { message, .. }  |
             Self::Shout { message, .. }|
@@ -255,6 +265,14 @@ impl IsRecipient for Broadcast {
                 && !message.trim().to_lowercase().starts_with("force")
             },
             Self::Channel { channel, .. } => channel.can_listen(&player).await && (p.listening_to(channel) || channel.is_always_on()),
+            Self::Area { room_id: Some(room_id), .. } => p.location == *room_id,
+            Self::Area { area_id, .. } => {
+                let room = world.read().await.rooms.get(&p.location).cloned();
+                match room {
+                    Some(r) => r.read().await.parent_id == *area_id,
+                    None => false
+                }
+            },
             Self::System { .. } => true,
         }
     }
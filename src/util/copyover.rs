@@ -0,0 +1,223 @@
+//! Copyover, a.k.a. warm reboot.
+//!
+//! Everything gets saved, session metadata is written down, and the (new)
+//! binary is `exec`'d with the listening and client sockets inherited. The
+//! new process then picks the sessions up again right where they were.
+use std::{fmt::Display, net::SocketAddr, os::{fd::{FromRawFd, RawFd}, unix::process::CommandExt}, sync::Arc};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tokio::{net::{TcpListener, TcpStream}, sync::RwLock};
+
+use crate::{DATA_PATH, cmd::translocate::translocate, item::vault::VAULT, player::{LoadError, Player, journal}, traits::{IdentityQuery, save::{DoesSave, SaveError}}, util::{ClientState, clientstate::EditorMode}, world::SharedWorld};
+
+pub(crate) static COPYOVER_FILEPATH: Lazy<String> = Lazy::new(|| format!("{}/copyover.json", *DATA_PATH));
+const COPYOVER_ARG: &str = "--copyover";
+
+/// A single carried over client session.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Session {
+    pub fd: RawFd,
+    pub addr: SocketAddr,
+    pub player: String,
+    pub state: ClientState,
+}
+
+/// What gets handed down to the next incarnation.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SessionFile {
+    pub listener_fd: RawFd,
+    pub sessions: Vec<Session>,
+}
+
+#[derive(Debug)]
+pub enum CopyoverError {
+    Io(std::io::Error),
+    Format(serde_json::Error),
+    Save(SaveError),
+    Load(LoadError),
+    NoListener,
+}
+
+impl std::error::Error for CopyoverError {}
+impl From<std::io::Error> for CopyoverError { fn from(value: std::io::Error) -> Self { Self::Io(value)}}
+impl From<serde_json::Error> for CopyoverError { fn from(value: serde_json::Error) -> Self { Self::Format(value)}}
+impl From<SaveError> for CopyoverError { fn from(value: SaveError) -> Self { Self::Save(value)}}
+impl From<LoadError> for CopyoverError { fn from(value: LoadError) -> Self { Self::Load(value)}}
+
+impl Display for CopyoverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error! {:?}", e),
+            Self::Format(e) => write!(f, "{:?}", e),
+            Self::Save(e) => write!(f, "Save failed: {:?}", e),
+            Self::Load(e) => write!(f, "{}", e),
+            Self::NoListener => write!(f, "No listening socket on record"),
+        }
+    }
+}
+
+/// Flip `FD_CLOEXEC` of `fd` on/off.
+fn set_cloexec(fd: RawFd, on: bool) -> std::io::Result<()> {
+    // SAFETY: plain fcntl() flag juggling on a descriptor we own.
+    let flags = unsafe { libc::fcntl(fd, libc::F_GETFD) };
+    if flags < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let flags = if on { flags | libc::FD_CLOEXEC } else { flags & !libc::FD_CLOEXEC };
+    if unsafe { libc::fcntl(fd, libc::F_SETFD, flags) } < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Command line for the next incarnation — same as ours, sans any
+/// previous copyover leftovers, plus the session file.
+fn exec_args(args: impl Iterator<Item = String>, session_file: &str) -> Vec<String> {
+    let mut out = vec![];
+    let mut args = args.peekable();
+    while let Some(a) = args.next() {
+        if a == COPYOVER_ARG {
+            args.next();
+            continue;
+        }
+        if a.starts_with(&format!("{COPYOVER_ARG}=")) {
+            continue;
+        }
+        out.push(a);
+    }
+    out.push(COPYOVER_ARG.into());
+    out.push(session_file.into());
+    out
+}
+
+/// Save everything, write down the sessions and `exec` ourselves anew.
+///
+/// # Returns
+/// Only if something went wrong along the way…
+pub(crate) async fn copyover(world: &SharedWorld) -> CopyoverError {
+    match copyover_inner(world).await {
+        Ok(()) => unreachable!("exec() returned without an error?!"),
+        Err(e) => e,
+    }
+}
+
+async fn copyover_inner(world: &SharedWorld) -> Result<(), CopyoverError> {
+    log::warn!("Copyover initiated — saving everything…");
+    world.write().await.save().await?;
+    if let Err(e) = VAULT.write().await.save().await {
+        log::error!("Failed to save lost-and-found vault: {:?}", e);
+    }
+
+    let (listener_fd, players, fds) = {
+        let w = world.read().await;
+        (w.listener_fd, w.players_by_sockaddr.clone(), w.session_fds.clone())
+    };
+    let Some(listener_fd) = listener_fd else { return Err(CopyoverError::NoListener) };
+
+    let mut sessions = vec![];
+    for (addr, p) in players {
+        let mut p = p.write().await;
+        p.save().await?;
+        let state = p.state();
+        let Some(fd) = fds.get(&addr) else { continue };
+        if !state.is_in_game() {
+            continue;
+        }
+        sessions.push(Session { fd: *fd, addr, player: p.id().into(), state });
+    }
+    // Saves wipe journals — see that the wipes land before we're gone.
+    journal::flush().await;
+
+    // Only the sessions written down get inherited — the rest would be left ownerless.
+    let session_fds = sessions.iter().map(|s| s.fd).collect::<Vec<_>>();
+    std::fs::write(COPYOVER_FILEPATH.as_str(), serde_json::to_string_pretty(&SessionFile { listener_fd, sessions })?)?;
+    set_cloexec(listener_fd, false)?;
+    for fd in &session_fds {
+        set_cloexec(*fd, false)?;
+    }
+
+    log::warn!("Copyover — exec'ing…");
+    let err = std::process::Command::new(std::env::current_exe()?)
+        .args(exec_args(std::env::args().skip(1), &COPYOVER_FILEPATH))
+        .exec();
+    // Still here? Not good. Tidy up a bit at least.
+    let _ = std::fs::remove_file(COPYOVER_FILEPATH.as_str());
+    let _ = set_cloexec(listener_fd, true);
+    for fd in session_fds {
+        let _ = set_cloexec(fd, true);
+    }
+    Err(err.into())
+}
+
+/// Read (and dispose of) the session file left behind by a copyover.
+pub(crate) fn read_session_file(path: &str) -> Result<SessionFile, CopyoverError> {
+    let sf: SessionFile = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    std::fs::remove_file(path)?;
+    Ok(sf)
+}
+
+/// Adopt the listening socket of our previous incarnation.
+pub(crate) fn inherit_listener(fd: RawFd) -> std::io::Result<TcpListener> {
+    // SAFETY: handed down to us by the previous incarnation, owned by no one else.
+    let listener = unsafe { std::net::TcpListener::from_raw_fd(fd) };
+    set_cloexec(fd, true)?;
+    listener.set_nonblocking(true)?;
+    TcpListener::from_std(listener)
+}
+
+/// Adopt a client socket and put its [Player] back where they stood.
+///
+/// # Returns
+/// The socket and the [ClientState] to carry on with.
+pub(crate) async fn resume_session(session: &Session, world: &SharedWorld) -> Result<(TcpStream, ClientState), CopyoverError> {
+    // SAFETY: handed down to us by the previous incarnation, owned by no one else.
+    let stream = unsafe { std::net::TcpStream::from_raw_fd(session.fd) };
+    set_cloexec(session.fd, true)?;
+    stream.set_nonblocking(true)?;
+    let stream = TcpStream::from_std(stream)?;
+
+    let mut player = Player::resume(&session.player).await?;
+    player.erase_states(ClientState::Playing);
+    // Editor state survives only if the edit buffer did.
+    if let ClientState::Editing { mode } = &session.state {
+        let has_buffer = match mode {
+            EditorMode::Room => player.redit.is_some(),
+            EditorMode::Help => player.hedit.is_some(),
+            EditorMode::Area => player.aedit.is_some(),
+            EditorMode::Item => player.iedit.is_some(),
+        };
+        if has_buffer {
+            player.push_state(session.state.clone());
+        }
+    }
+
+    let location = player.location.clone();
+    let p = Arc::new(RwLock::new(player));
+    let target = {
+        let w = world.read().await;
+        if w.rooms.contains_key(&location) { location } else { w.root.room.clone() }
+    };
+    let _ = translocate(world, None, target, p.clone()).await;
+
+    let state = p.read().await.state();
+    let mut w = world.write().await;
+    w.players_by_sockaddr.insert(session.addr, p.clone());
+    w.players.insert(session.player.clone(), p);
+    Ok((stream, state))
+}
+
+#[cfg(test)]
+mod copyover_tests {
+    use super::*;
+
+    #[test]
+    fn exec_args_sans_leftovers() {
+        let args = ["--port", "4000", "--copyover", "old.json", "--copyover=older.json", "--world", "w"]
+            .iter().map(|a| a.to_string());
+        assert_eq!(
+            vec!["--port", "4000", "--world", "w", "--copyover", "new.json"],
+            exec_args(args, "new.json")
+        );
+    }
+}
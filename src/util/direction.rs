@@ -3,6 +3,9 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
+pub mod custom;
+use custom::custom_directions;
+
 pub enum DirectionError {
     CannotDeductOpposite,
 }
@@ -19,7 +22,7 @@ pub enum Direction {
 impl Display for Direction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", match self {
-            Self::Custom(c) => c,
+            Self::Custom(c) => custom_directions().display(c).unwrap_or(c),
             _ => self.as_str()
         })
     }
@@ -42,6 +45,16 @@ impl Direction {
         }
     }
 
+    /// The canonical key of the direction — what [Direction::from] turns back into `self`.
+    ///
+    /// Unlike [Display], a custom direction's display name doesn't enter the picture.
+    pub fn as_key(&self) -> &str {
+        match self {
+            Self::Custom(c) => c,
+            d => d.as_str()
+        }
+    }
+
     pub fn opposite(&self) -> Result<Self, DirectionError> {
         Ok(match self {
             Self::Down => Self::Up,
@@ -54,11 +67,12 @@ impl Direction {
             Self::SouthWest => Self::NorthEast,
             Self::Up => Self::Down,
             Self::West => Self::East,
-            Self::Custom(x) => {
-                // TODO: somehow figure out the opposite for the given 'x' ...
-                log::warn!("Custom Direction::Custom({x}) - cannot deduce an opposite for…");
-                return Err(DirectionError::CannotDeductOpposite);
-//                Self::Custom(x.clone())
+            Self::Custom(x) => match custom_directions().opposite(x) {
+                Some(opp) => Self::Custom(opp.into()),
+                None => {
+                    log::debug!("Custom Direction::Custom({x}) - no opposite registered for…");
+                    return Err(DirectionError::CannotDeductOpposite);
+                }
             }
         })
     }
@@ -71,6 +85,14 @@ impl Direction {
             _ => result // return anything else as-is.
         }
     }
+
+    /// Try if we get a standard or a [registered][custom::CUSTOM_DIRECTIONS] custom [Direction] out of the given `value`.
+    pub fn try_from_known(value: &str) -> Result<Self, &'static str> {
+        Self::try_from_std(value).or_else(|_| match Self::try_from(value)? {
+            Self::Custom(c) if custom_directions().resolve(&c).is_some() => Ok(Self::Custom(c)),
+            _ => Err("That is not a known direction …")
+        })
+    }
 }
 
 impl TryFrom<&str> for Direction {
@@ -119,7 +141,10 @@ impl Direction {
             "northwest"|"nw" => Self::NorthWest,
             "southeast"|"se" => Self::SouthEast,
             "southwest"|"sw" => Self::SouthWest,
-            _ => Self::Custom(lc)
+            _ => match custom_directions().resolve(&lc) {
+                Some(name) => Self::Custom(name.into()),
+                None => Self::Custom(lc)
+            }
         }
     }
 
@@ -0,0 +1,107 @@
+//! Custom directions registry — `data/directions.json`.
+//!
+//! ```json
+//! {
+//!     "portal": { "aliases": ["gate"], "opposite": "portal", "display": "shimmering portal" },
+//!     "in":     { "opposite": "out" },
+//!     "out":    { "opposite": "in" }
+//! }
+//! ```
+use std::{collections::HashMap, sync::OnceLock};
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use crate::DATA_PATH;
+
+/// Custom directions known throughout the world, set at startup.
+pub(crate) static CUSTOM_DIRECTIONS: OnceLock<CustomDirections> = OnceLock::new();
+
+/// The [CUSTOM_DIRECTIONS] — or none at all, if not loaded (yet).
+pub(crate) fn custom_directions() -> &'static CustomDirections {
+    static NONE: Lazy<CustomDirections> = Lazy::new(CustomDirections::default);
+    CUSTOM_DIRECTIONS.get().unwrap_or(&NONE)
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct CustomDirection {
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Name of the opposite direction, if there is one.
+    #[serde(default)]
+    pub opposite: Option<String>,
+    /// Name to show instead of the canonical one.
+    #[serde(default)]
+    pub display: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct CustomDirections {
+    entries: HashMap<String, CustomDirection>,
+    /// alias → canonical name
+    aliases: HashMap<String, String>,
+}
+
+impl CustomDirections {
+    /// Load `directions.json`, if there is one.
+    pub async fn load() -> Result<Self, std::io::Error> {
+        let path = format!("{}/directions.json", *DATA_PATH);
+        match tokio::fs::read_to_string(&path).await {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                log::info!("No '{}' present, custom directions stay ad hoc.", path);
+                Ok(Self::default())
+            },
+            Err(e) => Err(e),
+            Ok(json) => Ok(Self::from_json(&json)?)
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let entries: HashMap<String, CustomDirection> = serde_json::from_str(json)?;
+        let entries = entries.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect::<HashMap<_, _>>();
+        let mut aliases = HashMap::new();
+        for (name, dir) in &entries {
+            for alias in &dir.aliases {
+                aliases.insert(alias.to_lowercase(), name.clone());
+            }
+        }
+        Ok(Self { entries, aliases })
+    }
+
+    /// Resolve `name` (or an alias) to its canonical name, if registered.
+    pub fn resolve(&self, name: &str) -> Option<&str> {
+        if let Some((k, _)) = self.entries.get_key_value(name) {
+            return Some(k);
+        }
+        self.aliases.get(name).map(|c| c.as_str())
+    }
+
+    /// Canonical name of the opposite of `name`, if any.
+    pub fn opposite(&self, name: &str) -> Option<&str> {
+        self.entries.get(self.resolve(name)?)?.opposite.as_deref()
+    }
+
+    /// Display name of `name`, if it has one.
+    pub fn display(&self, name: &str) -> Option<&str> {
+        self.entries.get(name)?.display.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod custom_direction_tests {
+    use super::*;
+
+    #[test]
+    fn resolves() {
+        let reg = CustomDirections::from_json(r#"{
+            "Portal": { "aliases": ["gate"], "opposite": "portal", "display": "shimmering portal" },
+            "hatch": { "aliases": ["trapdoor"] }
+        }"#).unwrap();
+        assert_eq!(Some("portal"), reg.resolve("gate"));
+        assert_eq!(Some("portal"), reg.opposite("gate"));
+        assert_eq!(Some("hatch"), reg.resolve("trapdoor"));
+        assert!(reg.opposite("hatch").is_none());
+        assert_eq!(Some("shimmering portal"), reg.display("portal"));
+        assert!(reg.resolve("window").is_none());
+    }
+}
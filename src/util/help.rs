@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 use walkdir::WalkDir;
 
-use crate::{traits::{IdentityQuery, save::{DoesSave, SaveError}, Description}, util::{Editor, GithubContent}, DATA_PATH};
+use crate::{player::knowledge::KnowledgeBase, traits::{IdentityQuery, save::{DoesSave, SaveError}, Description}, util::{Editor, GithubContent}, DATA_PATH};
 
 static HELP_PATH: Lazy<Arc<String>> = Lazy::new(|| Arc::new(format!("{}/help", *DATA_PATH)));
 static GITHUB_HELP_REPO: &str = "https://api.github.com/repos/msukanen/rustROM-help/contents";
@@ -25,12 +25,27 @@ pub struct Help {
     pub admin: bool,
     #[serde(default)]
     pub builder: bool,
+    /// Knowledge (by ID) required to see the entry at all, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires_knowledge: Option<String>,
+    /// Area (by ID) the entry belongs to, if any; editing it requires build rights there.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
 }
 
 impl Description for Help {
     fn description<'a>(&'a self) -> &'a str { &self.description }
 }
 
+impl Help {
+    /// Is the entry visible with the given access and `known` knowledge?
+    pub fn visible_to(&self, is_admin: bool, is_builder: bool, known: &KnowledgeBase) -> bool {
+        (!self.admin || is_admin)
+        && (!self.builder || is_builder)
+        && (is_builder || self.requires_knowledge.as_ref().is_none_or(|k| known.knows(k)))
+    }
+}
+
 impl IdentityQuery for Help {
     fn id<'a>(&'a self) -> &'a str { &self.id }
     fn title<'a>(&'a self) -> &'a str { &self.title }
@@ -99,6 +114,8 @@ impl Help {
             description: "".into(),
             admin: false,
             builder: false,
+            requires_knowledge: None,
+            area: None,
         }
     }
 
@@ -0,0 +1,145 @@
+//! Undo/redo history for the editors.
+use std::collections::VecDeque;
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// How many steps back an editor remembers.
+pub(crate) const MAX_HISTORY: usize = 50;
+
+/// Snapshots of an entry being edited, for undo/redo.
+#[derive(Debug, Clone)]
+pub struct EditHistory<T> {
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+}
+
+impl<T> Default for EditHistory<T> {
+    fn default() -> Self { Self { undo: VecDeque::new(), redo: vec![] }}
+}
+
+impl<T: Clone> EditHistory<T> {
+    /// Remember `before` as it was prior to a change.
+    ///
+    /// Any redo steps are lost, as usual.
+    pub fn record(&mut self, before: &T) {
+        if self.undo.len() >= MAX_HISTORY {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(before.clone());
+        self.redo.clear();
+    }
+
+    /// Step `current` one change back, if possible.
+    pub fn undo(&mut self, current: &mut T) -> bool {
+        let Some(prev) = self.undo.pop_back() else { return false };
+        self.redo.push(std::mem::replace(current, prev));
+        true
+    }
+
+    /// Re-apply the most recently undone change to `current`, if any.
+    pub fn redo(&mut self, current: &mut T) -> bool {
+        let Some(next) = self.redo.pop() else { return false };
+        self.undo.push_back(std::mem::replace(current, next));
+        true
+    }
+
+    /// Number of (undo, redo) steps available.
+    pub fn steps(&self) -> (usize, usize) { (self.undo.len(), self.redo.len()) }
+}
+
+/// A single field's change between two versions of an entry.
+#[derive(Debug, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// Compare `old` and `new` field by field, ignoring fields listed in `skip`.
+pub fn field_diff<T: Serialize>(old: &T, new: &T, skip: &[&str]) -> Vec<FieldChange> {
+    let as_map = |v: &T| match serde_json::to_value(v) {
+        Ok(Value::Object(m)) => m,
+        _ => serde_json::Map::new()
+    };
+    let (old, new) = (as_map(old), as_map(new));
+    let mut fields = old.keys().chain(new.keys())
+        .filter(|k| !skip.contains(&k.as_str()))
+        .cloned().collect::<Vec<_>>();
+    fields.sort();
+    fields.dedup();
+    fields.into_iter().filter_map(|field| {
+        let (o, n) = (old.get(&field), new.get(&field));
+        if o == n { return None; }
+        Some(FieldChange { field, old: o.map(fmt_value), new: n.map(fmt_value) })
+    }).collect()
+}
+
+/// Strings as-is, everything else as compact JSON.
+fn fmt_value(v: &Value) -> String {
+    match v {
+        Value::String(s) => s.clone(),
+        other => other.to_string()
+    }
+}
+
+/// Render `changes` for the user, or `none` if there are none.
+pub fn fmt_diff(changes: &[FieldChange], none: &str) -> String {
+    if changes.is_empty() {
+        return format!("{}\n", none);
+    }
+    let mut out = "<c yellow>-[ CHANGES ]-</c>\n".to_string();
+    for c in changes {
+        out.push_str(&format!("<c cyan>{}</c>:\n", c.field));
+        if let Some(old) = &c.old {
+            for line in old.lines() { out.push_str(&format!("  <c red>- {}</c>\n", line)); }
+        }
+        if let Some(new) = &c.new {
+            for line in new.lines() { out.push_str(&format!("  <c green>+ {}</c>\n", line)); }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod history_tests {
+    use super::*;
+
+    #[test]
+    fn undo_redo() {
+        let mut h = EditHistory::default();
+        let mut s = "a".to_string();
+        h.record(&s); s = "b".into();
+        h.record(&s); s = "c".into();
+        assert!(h.undo(&mut s));
+        assert_eq!("b", s);
+        assert!(h.undo(&mut s));
+        assert_eq!("a", s);
+        assert!(!h.undo(&mut s));
+        assert!(h.redo(&mut s));
+        assert_eq!("b", s);
+        // a fresh change forgets what was undone.
+        h.record(&s); s = "x".into();
+        assert!(!h.redo(&mut s));
+        assert_eq!((2, 0), h.steps());
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut h = EditHistory::default();
+        for i in 0..MAX_HISTORY + 5 { h.record(&i); }
+        assert_eq!((MAX_HISTORY, 0), h.steps());
+    }
+
+    #[derive(Serialize)]
+    struct Thing { a: String, b: u8, c: bool }
+
+    #[test]
+    fn diff_fields() {
+        let x = Thing { a: "one".into(), b: 1, c: true };
+        let y = Thing { a: "two".into(), b: 1, c: false };
+        let d = field_diff(&x, &y, &["c"]);
+        assert_eq!(1, d.len());
+        assert_eq!(FieldChange { field: "a".into(), old: Some("one".into()), new: Some("two".into()) }, d[0]);
+    }
+}
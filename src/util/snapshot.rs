@@ -0,0 +1,213 @@
+//! Rotating snapshots of the data directory.
+//!
+//! Snapshots are `.tar.gz` archives of the world file(s), areas, rooms, help,
+//! player saves, mob and room templates, item blueprints and custom directions. They are pruned with a keep-N/keep-daily policy: the
+//! [KEEP_LAST] most recent are always kept, plus the newest one for each of
+//! the [KEEP_DAILY] most recent days.
+use std::{collections::HashSet, fmt::Display, fs::{self, File}, io::Read, path::{Path, PathBuf}};
+
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use once_cell::sync::Lazy;
+
+use crate::{DATA_PATH, util::timestamp::unix_now};
+
+pub(crate) static SNAPSHOT_PATH: Lazy<String> = Lazy::new(|| format!("{}/snapshots", *DATA_PATH));
+/// Data subdirectories that go into a snapshot.
+const SNAPSHOT_DIRS: [&str; 7] = ["areas", "rooms", "help", "save", "mobs", "blueprints", "room_templates"];
+/// Stand-alone data files that go into a snapshot (on top of the world file(s)).
+const SNAPSHOT_FILES: [&str; 1] = ["directions.json"];
+const SNAPSHOT_PREFIX: &str = "snapshot-";
+const SNAPSHOT_SUFFIX: &str = ".tar.gz";
+/// Number of most recent snapshots to keep, no matter what.
+pub(crate) const KEEP_LAST: usize = 12;
+/// Number of days to keep the day's newest snapshot around for.
+pub(crate) const KEEP_DAILY: usize = 7;
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(std::io::Error),
+    NotFound(String),
+    NoSuchEntry(String),
+}
+
+impl std::error::Error for SnapshotError {}
+impl From<std::io::Error> for SnapshotError { fn from(value: std::io::Error) -> Self { Self::Io(value)}}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{:?}", e),
+            Self::NotFound(s) => write!(f, "No such snapshot as '{}'", s),
+            Self::NoSuchEntry(e) => write!(f, "Snapshot has no '{}' in it", e),
+        }
+    }
+}
+
+/// Some info about a snapshot on disk.
+#[derive(Debug, Clone)]
+pub struct SnapshotInfo {
+    pub name: String,
+    /// When the snapshot was taken (UNIX seconds).
+    pub taken_at: u64,
+    pub size: u64,
+}
+
+impl SnapshotInfo {
+    fn path(&self) -> PathBuf {
+        PathBuf::from(format!("{}/{}", *SNAPSHOT_PATH, self.name))
+    }
+}
+
+/// Take a snapshot of the data directory.
+///
+/// Blocking; run via [tokio::task::spawn_blocking] from async context.
+///
+/// # Returns
+/// Name of the newly created snapshot.
+pub fn create_snapshot() -> Result<String, SnapshotError> {
+    fs::create_dir_all(SNAPSHOT_PATH.as_str())?;
+    let name = format!("{}{}{}", SNAPSHOT_PREFIX, unix_now(), SNAPSHOT_SUFFIX);
+    let tmp = format!("{}/{}.tmp", *SNAPSHOT_PATH, name);
+    {
+        let gz = GzEncoder::new(File::create(&tmp)?, Compression::default());
+        let mut tar = tar::Builder::new(gz);
+        for entry in fs::read_dir(DATA_PATH.as_str())? {
+            let path = entry?.path();
+            if path.is_file() && (path.extension().is_some_and(|e| e == "world")
+                || path.file_name().is_some_and(|f| SNAPSHOT_FILES.iter().any(|s| f == *s))) {
+                tar.append_path_with_name(&path, path.file_name().unwrap())?;
+            }
+        }
+        for dir in SNAPSHOT_DIRS {
+            let path = format!("{}/{}", *DATA_PATH, dir);
+            if Path::new(&path).is_dir() {
+                tar.append_dir_all(dir, &path)?;
+            }
+        }
+        tar.into_inner()?.finish()?;
+    }
+    fs::rename(&tmp, format!("{}/{}", *SNAPSHOT_PATH, name))?;
+    Ok(name)
+}
+
+/// List snapshots, newest first.
+pub fn list_snapshots() -> Vec<SnapshotInfo> {
+    let Ok(dir) = fs::read_dir(SNAPSHOT_PATH.as_str()) else { return vec![] };
+    let mut list = dir.filter_map(|e| e.ok())
+        .filter_map(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            let taken_at = name.strip_prefix(SNAPSHOT_PREFIX)?.strip_suffix(SNAPSHOT_SUFFIX)?.parse::<u64>().ok()?;
+            let size = e.metadata().map(|m| m.len()).unwrap_or(0);
+            Some(SnapshotInfo { name, taken_at, size })
+        })
+        .collect::<Vec<SnapshotInfo>>();
+    list.sort_by_key(|s| std::cmp::Reverse(s.taken_at));
+    list
+}
+
+/// Find a snapshot by name, or "latest".
+///
+/// A prefix of the name (or of its timestamp) will do, as long as only one snapshot matches.
+pub fn find_snapshot(name: &str) -> Option<SnapshotInfo> {
+    pick_snapshot(list_snapshots(), name)
+}
+
+fn pick_snapshot(list: Vec<SnapshotInfo>, name: &str) -> Option<SnapshotInfo> {
+    if name == "latest" {
+        return list.into_iter().next();
+    }
+    if let Some(s) = list.iter().find(|s| s.name == name) {
+        return Some(s.clone());
+    }
+    let mut found = list.into_iter().filter(|s|
+        s.name.starts_with(name) || s.name.strip_prefix(SNAPSHOT_PREFIX).is_some_and(|t| t.starts_with(name)));
+    match (found.next(), found.next()) {
+        (Some(s), None) => Some(s),
+        _ => None
+    }
+}
+
+/// Figure out which snapshots are to be pruned.
+///
+/// # Args
+/// - `snapshots`— newest first, as given by [list_snapshots].
+pub fn to_be_pruned(snapshots: &[SnapshotInfo]) -> Vec<SnapshotInfo> {
+    let mut days = HashSet::new();
+    let mut pruned = vec![];
+    for (i, s) in snapshots.iter().enumerate() {
+        let day = s.taken_at / 86_400;
+        let newest_of_day = days.insert(day);
+        if i < KEEP_LAST || (newest_of_day && days.len() <= KEEP_DAILY) {
+            continue;
+        }
+        pruned.push(s.clone());
+    }
+    pruned
+}
+
+/// Prune old snapshots according to the keep-N/keep-daily policy.
+///
+/// # Returns
+/// Number of snapshots removed.
+pub fn prune_snapshots() -> usize {
+    let mut count = 0;
+    for s in to_be_pruned(&list_snapshots()) {
+        match fs::remove_file(s.path()) {
+            Ok(_) => count += 1,
+            Err(e) => log::error!("Could not prune snapshot '{}': {:?}", s.name, e),
+        }
+    }
+    count
+}
+
+/// Dig out a single file from a snapshot.
+///
+/// # Args
+/// - `snapshot` to dig from.
+/// - `entry`— path within the snapshot, e.g. `rooms/root.room`.
+pub fn read_entry(snapshot: &SnapshotInfo, entry: &str) -> Result<String, SnapshotError> {
+    let file = File::open(snapshot.path()).map_err(|_| SnapshotError::NotFound(snapshot.name.clone()))?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+    for e in archive.entries()? {
+        let mut e = e?;
+        if e.path()?.as_ref() == Path::new(entry) {
+            let mut content = String::new();
+            e.read_to_string(&mut content)?;
+            return Ok(content);
+        }
+    }
+    Err(SnapshotError::NoSuchEntry(entry.into()))
+}
+
+#[cfg(test)]
+mod snapshot_tests {
+    use super::*;
+
+    fn snap(taken_at: u64) -> SnapshotInfo {
+        SnapshotInfo { name: format!("{SNAPSHOT_PREFIX}{taken_at}{SNAPSHOT_SUFFIX}"), taken_at, size: 0 }
+    }
+
+    #[test]
+    fn prune_keeps_last_and_dailies() {
+        // 4 per day for 20 days, newest first.
+        let snaps = (0..80u64).rev().map(|i| snap(i * 21_600)).collect::<Vec<SnapshotInfo>>();
+        let pruned = to_be_pruned(&snaps);
+        // KEEP_LAST covers 3 days' worth; then 4 more days × newest-of-day.
+        assert_eq!(80 - KEEP_LAST - (KEEP_DAILY - 3), pruned.len());
+        assert!(pruned.iter().all(|p| !snaps[..KEEP_LAST].iter().any(|s| s.name == p.name)));
+        assert!(to_be_pruned(&snaps[..KEEP_LAST]).is_empty());
+    }
+
+    #[test]
+    fn pick_exact_or_unique_prefix() {
+        let snaps = vec![snap(1700000200), snap(1700000100), snap(1600000000)];
+        assert_eq!(1700000200, pick_snapshot(snaps.clone(), "latest").unwrap().taken_at);
+        assert_eq!(1700000100, pick_snapshot(snaps.clone(), &snaps[1].name).unwrap().taken_at);
+        assert_eq!(1600000000, pick_snapshot(snaps.clone(), "16").unwrap().taken_at);
+        assert_eq!(1700000100, pick_snapshot(snaps.clone(), "snapshot-17000001").unwrap().taken_at);
+        // ambiguous…
+        assert!(pick_snapshot(snaps.clone(), "17").is_none());
+        // …and no more substring matches.
+        assert!(pick_snapshot(snaps, "0000").is_none());
+    }
+}
@@ -0,0 +1,21 @@
+//! Wall-clock timestamps for things that need to outlive a restart.
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Seconds since UNIX epoch, right about now.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Human-ish rendition of a span of `secs`, e.g. "3d 4h" or "12m".
+pub fn fmt_span(secs: u64) -> String {
+    let (d, h, m) = (secs / 86_400, (secs % 86_400) / 3600, (secs % 3600) / 60);
+    match (d, h, m) {
+        (0, 0, 0) => format!("{}s", secs),
+        (0, 0, m) => format!("{}m", m),
+        (0, h, m) => format!("{}h {}m", h, m),
+        (d, h, _) => format!("{}d {}h", d, h),
+    }
+}
@@ -2,4 +2,11 @@ pub mod world;
 pub mod area;
 pub mod exit;
 pub mod room;
+pub mod bundle;
+pub mod path;
+pub mod minimap;
+pub mod calendar;
+pub mod weather;
+pub mod integrity;
+pub mod draft;
 pub use world::{World, SharedWorld};
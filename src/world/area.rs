@@ -1,28 +1,79 @@
 //! Area stuff.
-use std::{collections::HashMap, path::PathBuf, str::FromStr, sync::{Arc, Weak}};
+use std::{collections::{HashMap, HashSet}, path::PathBuf, str::FromStr, sync::{Arc, Weak}};
 
 use async_trait::async_trait;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use tokio::{fs, sync::RwLock};
 
-use crate::{DATA_PATH, traits::{Description, IdentityQuery, save::{DoesSave, SaveError}, tickable::Tickable}, world::{World, room::Room}};
+use dicebag::{DiceExt, RandomOf};
+
+use crate::{DATA_PATH, game_loop::TICKS_PER_SECOND, mob::template::MOB_TEMPLATES, traits::{Description, IdentityQuery, save::{DoesSave, SaveError}, tickable::Tickable}, util::direction::Direction, world::{World, calendar::Season, draft::Draft, exit::state::ExitStateQuery, room::Room, weather::Weather}};
+
+pub mod reset;
+use reset::Reset;
 
 pub(crate) static AREA_PATH: Lazy<Arc<String>> = Lazy::new(|| Arc::new(format!("{}/areas", *DATA_PATH)));
 const DEFAULT_TICK_MODULO: u8 = 10;// normally an Area acts once every 10th tick.
 
+const DEFAULT_RESET_PERIOD: u64 = 15 * 60;// seconds.
+
 const fn default_area_tick_modulo() -> u8 {DEFAULT_TICK_MODULO}// to appease 'serde(default = ...)'
-#[derive(Debug, Deserialize, Serialize)]
+const fn default_reset_period() -> u64 {DEFAULT_RESET_PERIOD}
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Area {
     pub id: String,
-    title: String,
-    description: String,
+    pub title: String,
+    pub description: String,
     
     #[serde(skip)] pub parent: Weak<RwLock<World>>,
     #[serde(skip)] pub rooms: HashMap<String, Weak<RwLock<Room>>>,
     
     #[serde(default = "default_area_tick_modulo")]
-    tick_modulo: u8,
+    pub tick_modulo: u8,
+
+    /// What the area is supposed to look like, reset-wise.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resets: Vec<Reset>,
+    /// Seconds between resets; `0` = no timed resets.
+    #[serde(default = "default_reset_period")]
+    pub reset_period: u64,
+    /// Reset (also) as soon as the last player leaves?
+    #[serde(default)]
+    pub reset_when_empty: bool,
+    /// Uptime of the latest reset, if any yet.
+    #[serde(skip)] last_reset: Option<u64>,
+    #[serde(skip)] occupied: bool,
+    /// Messages waiting to be delivered to players within the area.
+    #[serde(skip)] pub outbox: Vec<AreaMessage>,
+    /// Doors put back by [resets][Area::reset], for the [World] to mirror onto their other side.
+    #[serde(skip)] pub reset_doors: Vec<(String, Direction)>,
+    #[serde(skip)] pub weather: Weather,
+
+    /// Builders in charge of the area; they may also grant/revoke contributors.
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub owners: HashSet<String>,
+    /// Builders allowed to build within the area.
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub contributors: HashSet<String>,
+    /// Set while the area is a draft, hidden from players.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub draft: Option<Draft>,
+}
+
+/// A message waiting to be delivered to players within an [Area].
+#[derive(Debug, Clone)]
+pub struct AreaMessage {
+    /// Deliver only into this room, if set.
+    pub room_id: Option<String>,
+    pub message: String,
+}
+
+impl AreaMessage {
+    /// Message for the whole area.
+    pub fn area(message: String) -> Self { Self { room_id: None, message }}
+    /// Message for a single room.
+    pub fn room(room_id: &str, message: String) -> Self { Self { room_id: Some(room_id.into()), message }}
 }
 
 #[async_trait]
@@ -30,6 +81,25 @@ impl Tickable for Area {
     async fn tick(&mut self, uptime: u64) {
         // Time to tick?
         if (uptime % self.tick_modulo as u64) != 0 {return ;}
+
+        for room in self.rooms.values().filter_map(|r| r.upgrade()) {
+            for mob in room.write().await.mobs.values_mut() {
+                mob.tick(uptime).await;
+            }
+        }
+        self.mobs_wander().await;
+
+        let occupied = self.is_occupied().await;
+        let emptied = self.occupied && !occupied;
+        self.occupied = occupied;
+        let due = match self.last_reset {
+            None => true,// first tick after boot.
+            Some(at) => self.reset_period > 0 && uptime.saturating_sub(at) >= self.reset_period * TICKS_PER_SECOND,
+        };
+        if due || (emptied && self.reset_when_empty) {
+            self.reset().await;
+            self.last_reset = Some(uptime);
+        }
     }
 }
 
@@ -50,16 +120,157 @@ impl Area {
         Ok(())
     }
 
-    /// A blank slate.
-    #[cfg(test)]
-    pub(crate) fn blank() -> Self { Self {
-        id: "".into(),
+    /// Is `who` one of the area owners?
+    pub fn is_owner(&self, who: &str) -> bool {
+        self.owners.contains(&who.to_lowercase())
+    }
+
+    /// May `who` build within the area, as an owner or a contributor?
+    ///
+    /// Note that areas with nobody listed are for admins only.
+    pub fn may_build(&self, who: &str) -> bool {
+        let who = who.to_lowercase();
+        self.owners.contains(&who) || self.contributors.contains(&who)
+    }
+
+    /// Is there any [Player][crate::player::Player] around in the area?
+    pub async fn is_occupied(&self) -> bool {
+        for room in self.rooms.values() {
+            let Some(room) = room.upgrade() else { continue };
+            if room.read().await.players.values().any(|p| p.strong_count() > 0) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Put the area back into its defined [reset][Reset] state.
+    ///
+    /// Reset messages, if any, are queued into the [outbox][Area::outbox], and doors
+    /// that changed into [reset_doors][Area::reset_doors].
+    ///
+    /// # Returns
+    /// Number of resets that actually changed something.
+    pub async fn reset(&mut self) -> usize {
+        let mut changed = 0;
+        let mobs = MOB_TEMPLATES.read().await;
+        for reset in &self.resets {
+            let Some(room) = self.rooms.get(reset.room_id()).and_then(|r| r.upgrade()) else {
+                log::warn!("Area '{}': reset targets unknown room '{}'.", self.id, reset.room_id());
+                continue;
+            };
+            let Some(message) = reset.apply(&mut *room.write().await, &mobs) else { continue };
+            changed += 1;
+            if let Reset::Door { room, exit, .. } = reset {
+                self.reset_doors.push((room.clone(), exit.clone()));
+            }
+            if !message.is_empty() {
+                self.outbox.push(AreaMessage::area(message));
+            }
+        }
+        if changed > 0 {
+            log::debug!("Area '{}' reset ({} change{}).", self.id, changed, if changed == 1 {""} else {"s"});
+        }
+        changed
+    }
+
+    /// Tell `message` to everyone out in the open within the area.
+    pub async fn tell_outdoors(&mut self, message: &str) {
+        for (id, room) in &self.rooms {
+            let Some(room) = room.upgrade() else { continue };
+            let r = room.read().await;
+            if r.is_outdoors() && !r.players.is_empty() {
+                self.outbox.push(AreaMessage::room(id, message.into()));
+            }
+        }
+    }
+
+    /// Let the weather run its course for another hour.
+    pub async fn shift_weather(&mut self, season: Season) {
+        let next = self.weather.next(season);
+        if let Some(msg) = self.weather.change_message(next) {
+            self.tell_outdoors(msg).await;
+        }
+        self.weather = next;
+    }
+
+    /// Let the wanderers wander about — within the area only, and never
+    /// through locked exits.
+    async fn mobs_wander(&mut self) {
+        let mut moves = vec![];
+        for (room_id, room) in &self.rooms {
+            let Some(room) = room.upgrade() else { continue };
+            let r = room.read().await;
+            for mob in r.mobs.values() {
+                let Some(chance) = mob.wander_chance() else { continue };
+                if 1.d100() > chance as i32 {
+                    continue;
+                }
+                let ways = r.exits.iter()
+                    .filter(|(_, e)| !e.is_locked() && self.rooms.contains_key(&e.destination))
+                    .map(|(d, e)| (d.clone(), e.destination.clone()))
+                    .collect::<Vec<_>>();
+                if ways.is_empty() {
+                    continue;
+                }
+                let (dir, to) = ways.random_of();
+                moves.push((room_id.clone(), mob.id().to_string(), dir, to));
+            }
+        }
+
+        for (from, mob_id, dir, to) in moves {
+            let (Some(src), Some(dst)) = (
+                self.rooms.get(&from).and_then(|r| r.upgrade()),
+                self.rooms.get(&to).and_then(|r| r.upgrade())
+            ) else { continue };
+            let Some(mob) = src.write().await.mobs.remove(&mob_id) else { continue };
+            let title = mob.title().to_string();
+            dst.write().await.add_mob(mob);
+            if !src.read().await.players.is_empty() {
+                self.outbox.push(AreaMessage::room(&from, format!("{title} leaves {dir}.")));
+            }
+            if !dst.read().await.players.is_empty() {
+                self.outbox.push(AreaMessage::room(&to, format!("{title} arrives.")));
+            }
+        }
+    }
+
+    /// A brand new, empty area.
+    pub fn new(id: &str) -> Self { Self {
+        id: id.into(),
         title: "".into(),
         description: "".into(),
         rooms: HashMap::new(),
         parent: Weak::new(),
-        tick_modulo: 10,
+        tick_modulo: DEFAULT_TICK_MODULO,
+        resets: vec![],
+        reset_period: DEFAULT_RESET_PERIOD,
+        reset_when_empty: false,
+        last_reset: None,
+        occupied: false,
+        outbox: vec![],
+        reset_doors: vec![],
+        weather: Weather::default(),
+        owners: HashSet::new(),
+        contributors: HashSet::new(),
+        draft: None,
     }}
+
+    /// A blank slate.
+    #[cfg(test)]
+    pub(crate) fn blank() -> Self { Self::new("") }
+
+    /// Copy over the editable bits from `other`.
+    pub(crate) fn shallow_copy(&mut self, other: &Self) {
+        self.title = other.title.clone();
+        self.description = other.description.clone();
+        self.tick_modulo = other.tick_modulo;
+        self.resets = other.resets.clone();
+        self.reset_period = other.reset_period;
+        self.reset_when_empty = other.reset_when_empty;
+        self.owners = other.owners.clone();
+        self.contributors = other.contributors.clone();
+    }
 }
 
 impl Description for Area {
@@ -0,0 +1,157 @@
+//! Area resets — the state an [Area][crate::world::area::Area] keeps returning to.
+use serde::{Deserialize, Serialize};
+
+use crate::{item::{BlueprintID, Item, inventory::Storage}, mob::template::MobRegistry, traits::IdentityQuery, util::direction::Direction, world::{exit::state::ExitState, room::Room}};
+
+const fn default_mob_count() -> usize {1}
+
+/// A single reset definition.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum Reset {
+    /// Make sure a copy of `item` lies around in `room`.
+    Item {
+        room: String,
+        item: Item,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// Put `exit` of `room` back into `state` (jams and all).
+    Door {
+        room: String,
+        exit: Direction,
+        state: ExitState,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// Keep `count` mobs of template `mob` around in `room`.
+    Mob {
+        room: String,
+        mob: String,
+        #[serde(default = "default_mob_count")]
+        count: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+}
+
+impl Reset {
+    /// ID of the [Room] the reset concerns.
+    pub fn room_id(&self) -> &str {
+        match self {
+            Self::Item { room, .. } |
+            Self::Door { room, .. } |
+            Self::Mob { room, .. } => room,
+        }
+    }
+
+    pub(crate) fn room_id_mut(&mut self) -> &mut String {
+        match self {
+            Self::Item { room, .. } |
+            Self::Door { room, .. } |
+            Self::Mob { room, .. } => room,
+        }
+    }
+
+    /// Apply the reset onto `room`, with `mobs` as the template source.
+    ///
+    /// # Returns
+    /// `Some(message)` if something changed — `message` itself might be empty.
+    pub fn apply(&self, room: &mut Room, mobs: &MobRegistry) -> Option<String> {
+        match self {
+            Self::Item { item, message, .. } => {
+                if room.items().values().any(|i| i.bp_id() == item.bp_id()) {
+                    return None;
+                }
+                let mut item = item.clone();
+                item.reissue_id();
+                if let Err(e) = room.try_insert(item) {
+                    log::warn!("Reset: could not place an item into '{}': {e}", room.id());
+                    return None;
+                }
+                Some(message.clone().unwrap_or_default())
+            },
+            Self::Door { exit, state, message, .. } => {
+                let current = room.exits.get(exit).map(|e| &e.state);
+                match current {
+                    None => {
+                        log::warn!("Reset: room '{}' has no exit '{exit}'.", room.id());
+                        None
+                    },
+                    Some(c) if c == state => None,
+                    Some(_) => {
+                        room.set_exit_state(exit.clone(), state.clone());
+                        Some(message.clone().unwrap_or_default())
+                    }
+                }
+            },
+            Self::Mob { mob, count, message, .. } => {
+                let present = room.mobs.values().filter(|m| m.template() == mob).count();
+                if present >= *count {
+                    return None;
+                }
+                for _ in present..*count {
+                    let Some(m) = mobs.spawn(mob) else {
+                        log::warn!("Reset: no such mob template as '{}'.", mob);
+                        return None;
+                    };
+                    room.add_mob(m);
+                }
+                Some(message.clone().unwrap_or_default())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod reset_tests {
+    use crate::{item::item::ItemType, mob::core::MobCore, world::exit::Exit};
+
+    use super::*;
+
+    #[test]
+    fn apply_item_and_door() {
+        let mut room = Room::blank(Some("cellar"));
+        room.exits.insert(Direction::North, Exit::from("stairs"));
+        let item = Item::new(ItemType::Weapon);
+
+        let mobs = MobRegistry::default();
+
+        let put = Reset::Item { room: "cellar".into(), item: item.clone(), message: None };
+        assert!(put.apply(&mut room, &mobs).is_some());
+        assert!(put.apply(&mut room, &mobs).is_none());
+        assert_eq!(1, room.items().len());
+        assert!(!room.contains(item.id()));
+
+        let slam = Reset::Door {
+            room: "cellar".into(),
+            exit: Direction::North,
+            state: ExitState::Closed { key_id: None, jam: None },
+            message: Some("You hear a door slam shut.".into()),
+        };
+        assert_eq!(Some("You hear a door slam shut.".into()), slam.apply(&mut room, &mobs));
+        assert!(slam.apply(&mut room, &mobs).is_none());
+    }
+
+    #[test]
+    fn apply_mob() {
+        let mut room = Room::blank(Some("sewer"));
+        let mut mobs = MobRegistry::default();
+        let rats = Reset::Mob { room: "sewer".into(), mob: "rat".into(), count: 2, message: None };
+        assert!(rats.apply(&mut room, &mobs).is_none());
+
+        mobs.insert(serde_json::from_str::<MobCore>(r#"{
+            "name": "rat", "title": "a rat", "description": "Squeak.",
+            "hp": {"HP": {"current": 5.0, "max": 5.0}},
+            "mp": {"MP": {"current": 0.0, "max": 0.0, "drain": 0.0}},
+            "invis": false
+        }"#).unwrap());
+        assert!(rats.apply(&mut room, &mobs).is_some());
+        assert_eq!(2, room.mobs.len());
+        assert!(rats.apply(&mut room, &mobs).is_none());
+
+        let id = room.mobs.keys().next().cloned().unwrap();
+        room.mobs.remove(&id);
+        assert!(rats.apply(&mut room, &mobs).is_some());
+        assert_eq!(2, room.mobs.len());
+    }
+}
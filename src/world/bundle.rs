@@ -0,0 +1,385 @@
+//! Area bundles — an [Area], its [Room]s and the item [Blueprint]s they
+//! refer to, packed into a single file for moving builds between servers.
+use std::{collections::{BTreeMap, BTreeSet, HashSet}, fmt::Display, sync::Arc};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::{DATA_PATH, item::{BlueprintID, Item, blueprint::{BLUEPRINTS, Blueprint}, inventory::Storage}, string::slug::as_id, traits::{IdentityQuery, save::{DoesSave, SaveError}}, util::timestamp::unix_now, world::{World, area::{Area, reset::Reset}, exit::state::{ExitStateQuery, KEY_THAT_IS_NOT_A_KEY}, integrity::walk_items, room::Room}};
+
+pub(crate) static BUNDLE_PATH: Lazy<String> = Lazy::new(|| format!("{}/bundles", *DATA_PATH));
+const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// What's in the box.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BundleManifest {
+    pub format: u32,
+    pub area_id: String,
+    pub room_ids: Vec<String>,
+    pub blueprint_ids: Vec<String>,
+    /// When the bundle was made (UNIX seconds).
+    pub exported_at: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct AreaBundle {
+    pub manifest: BundleManifest,
+    pub area: Area,
+    pub rooms: Vec<Room>,
+    pub blueprints: Vec<Blueprint>,
+}
+
+#[derive(Debug)]
+pub enum BundleError {
+    Io(std::io::Error),
+    Format(serde_json::Error),
+    NoSuchArea(String),
+    UnsupportedFormat(u32),
+    /// Manifest and the actual content disagree.
+    Mismatch(String),
+}
+
+impl std::error::Error for BundleError {}
+impl From<std::io::Error> for BundleError { fn from(value: std::io::Error) -> Self { Self::Io(value)}}
+impl From<serde_json::Error> for BundleError { fn from(value: serde_json::Error) -> Self { Self::Format(value)}}
+impl From<SaveError> for BundleError {
+    fn from(value: SaveError) -> Self {
+        match value {
+            SaveError::Io(e) => Self::Io(e),
+            SaveError::JsonFormat(e) => Self::Format(e),
+            e => Self::Io(std::io::Error::other(format!("{e:?}"))),
+        }
+    }
+}
+
+impl Display for BundleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{:?}", e),
+            Self::Format(e) => write!(f, "{:?}", e),
+            Self::NoSuchArea(id) => write!(f, "No such area as '{}'", id),
+            Self::UnsupportedFormat(v) => write!(f, "Unsupported bundle format v{}", v),
+            Self::Mismatch(what) => write!(f, "Manifest mismatch: {}", what),
+        }
+    }
+}
+
+/// What [install()][AreaBundle::install] did with the bundle's blueprints.
+#[derive(Debug, Default, PartialEq)]
+pub struct Installed {
+    /// Blueprints registered and saved.
+    pub blueprints: Vec<String>,
+    /// Blueprints left alone — ones already known (or with unusable IDs).
+    pub skipped_blueprints: Vec<String>,
+}
+
+/// Problems found when checking a bundle against a [World].
+#[derive(Debug, PartialEq)]
+pub enum BundleIssue {
+    AreaCollision(String),
+    RoomCollision(String),
+    /// Room, exit destination.
+    DanglingExit(String, String),
+}
+
+impl BundleIssue {
+    /// Collisions are a no-go, period.
+    pub fn is_fatal(&self) -> bool {
+        !matches!(self, Self::DanglingExit(..))
+    }
+}
+
+impl Display for BundleIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AreaCollision(id) => write!(f, "area '{}' already exists", id),
+            Self::RoomCollision(id) => write!(f, "room '{}' already exists", id),
+            Self::DanglingExit(r, d) => write!(f, "room '{}' has an exit to nowhere ('{}')", r, d),
+        }
+    }
+}
+
+impl AreaBundle {
+    /// Pack `area_id` and all the rooms whose `parent_id` matches.
+    ///
+    /// Blueprints come along for items in the rooms (and in containers therein), items
+    /// in the area's resets, and keys to the area's doors.
+    pub async fn export(world: &World, area_id: &str) -> Result<Self, BundleError> {
+        let Some(area) = world.areas.get(area_id) else {
+            return Err(BundleError::NoSuchArea(area_id.into()));
+        };
+        let area: Area = serde_json::from_value(serde_json::to_value(&*area.read().await)?)?;
+
+        let mut rooms = vec![];
+        // items lying around or reset, containers' contents included…
+        let mut items = vec![];
+        // …and keys the doors want.
+        let mut key_ids = BTreeSet::new();
+        for reset in &area.resets {
+            match reset {
+                Reset::Item { item, .. } => {
+                    items.push(item);
+                    if let Item::Container(c) = item {
+                        walk_items(c.items(), &mut items);
+                    }
+                },
+                Reset::Door { state, .. } if state.key_id() != KEY_THAT_IS_NOT_A_KEY => { key_ids.insert(state.key_id().to_string()); },
+                _ => ()
+            }
+        }
+        for room in world.rooms.values() {
+            let room = room.read().await;
+            if room.parent_id != area_id {
+                continue;
+            }
+            rooms.push(room.clone());
+        }
+        rooms.sort_by(|a, b| a.id.cmp(&b.id));
+        for room in &rooms {
+            walk_items(room.contents.items(), &mut items);
+            key_ids.extend(room.exits.values()
+                .filter(|e| e.state.key_id() != KEY_THAT_IS_NOT_A_KEY)
+                .map(|e| e.state.key_id().to_string()));
+        }
+
+        let mut blueprints = BTreeMap::new();
+        let registry = BLUEPRINTS.read().await;
+        for item in items.into_iter().filter(|i| !i.bp_id().is_empty()) {
+            // registered ones are the real deal; distilled ones will have to do otherwise.
+            blueprints.entry(item.bp_id().to_string()).or_insert_with(||
+                registry.get(item.bp_id()).cloned().unwrap_or_else(|| Blueprint::from(item)));
+        }
+        for id in key_ids {
+            match registry.get(&id) {
+                Some(bp) => { blueprints.entry(id).or_insert_with(|| bp.clone()); },
+                None => if !blueprints.contains_key(&id) {
+                    log::warn!("Area '{}' wants key '{}', but there's no blueprint for it.", area_id, id);
+                }
+            }
+        }
+
+        Ok(Self {
+            manifest: BundleManifest {
+                format: BUNDLE_FORMAT_VERSION,
+                area_id: area_id.into(),
+                room_ids: rooms.iter().map(|r| r.id.clone()).collect(),
+                blueprint_ids: blueprints.keys().cloned().collect(),
+                exported_at: unix_now(),
+            },
+            area,
+            rooms,
+            blueprints: blueprints.into_values().collect(),
+        })
+    }
+
+    /// Write the bundle into [BUNDLE_PATH].
+    ///
+    /// # Returns
+    /// Path of the bundle file.
+    pub async fn write(&self) -> Result<String, BundleError> {
+        tokio::fs::create_dir_all(BUNDLE_PATH.as_str()).await?;
+        let path = format!("{}/{}.bundle", *BUNDLE_PATH, self.manifest.area_id);
+        tokio::fs::write(&path, serde_json::to_string_pretty(self)?).await?;
+        Ok(path)
+    }
+
+    /// Read a bundle by name from [BUNDLE_PATH] and see that it's in one piece.
+    pub async fn read(name: &str) -> Result<Self, BundleError> {
+        let name = name.strip_suffix(".bundle").unwrap_or(name);
+        let path = format!("{}/{}.bundle", *BUNDLE_PATH, name);
+        let bundle: Self = serde_json::from_str(&tokio::fs::read_to_string(path).await?)?;
+        if bundle.manifest.format != BUNDLE_FORMAT_VERSION {
+            return Err(BundleError::UnsupportedFormat(bundle.manifest.format));
+        }
+        if bundle.manifest.area_id != bundle.area.id {
+            return Err(BundleError::Mismatch(format!("area '{}' ≠ '{}'", bundle.manifest.area_id, bundle.area.id)));
+        }
+        let listed = bundle.manifest.room_ids.iter().collect::<HashSet<&String>>();
+        if let Some(r) = bundle.rooms.iter().find(|r| !listed.contains(&r.id)) {
+            return Err(BundleError::Mismatch(format!("room '{}' not in manifest", r.id)));
+        }
+        if listed.len() != bundle.rooms.len() {
+            return Err(BundleError::Mismatch("room count".into()));
+        }
+        Ok(bundle)
+    }
+
+    /// Prefix area and room IDs with `prefix`.
+    ///
+    /// Exits and resets concerning rooms within the bundle follow along; exits out of
+    /// the bundle are left as they are.
+    pub fn remap(&mut self, prefix: &str) {
+        let own = self.rooms.iter().map(|r| r.id.clone()).collect::<HashSet<String>>();
+        let area_id = format!("{prefix}{}", self.area.id);
+        self.area.id = area_id.clone();
+        self.manifest.area_id = area_id.clone();
+        for room in &mut self.rooms {
+            room.id = format!("{prefix}{}", room.id);
+            room.parent_id = area_id.clone();
+            for exit in room.exits.values_mut() {
+                if own.contains(&exit.destination) {
+                    exit.destination = format!("{prefix}{}", exit.destination);
+                }
+            }
+        }
+        for reset in &mut self.area.resets {
+            let room = reset.room_id_mut();
+            if own.contains(room) {
+                *room = format!("{prefix}{room}");
+            }
+        }
+        self.manifest.room_ids = self.rooms.iter().map(|r| r.id.clone()).collect();
+    }
+
+    /// Check the bundle against `world` for collisions and dangling exits.
+    pub fn check(&self, world: &World) -> Vec<BundleIssue> {
+        let mut issues = vec![];
+        if world.areas.contains_key(&self.area.id) {
+            issues.push(BundleIssue::AreaCollision(self.area.id.clone()));
+        }
+        let own = self.rooms.iter().map(|r| r.id.as_str()).collect::<HashSet<&str>>();
+        for room in &self.rooms {
+            if world.rooms.contains_key(&room.id) {
+                issues.push(BundleIssue::RoomCollision(room.id.clone()));
+            }
+            for exit in room.exits.values() {
+                if !own.contains(exit.destination.as_str()) && !world.rooms.contains_key(&exit.destination) {
+                    issues.push(BundleIssue::DanglingExit(room.id.clone(), exit.destination.clone()));
+                }
+            }
+        }
+        issues
+    }
+
+    /// Put the bundle's area, rooms and blueprints into play and on disk.
+    ///
+    /// Blueprints already known are not overwritten — see [Installed].
+    ///
+    /// [check()][AreaBundle::check] first — this doesn't.
+    pub async fn install(self, world: &mut World) -> Result<Installed, BundleError> {
+        let mut installed = Installed::default();
+        {
+            let mut registry = BLUEPRINTS.write().await;
+            for mut bp in self.blueprints {
+                if registry.get(&bp.id).is_some() || as_id(&bp.id).ok().as_ref() != Some(&bp.id) {
+                    installed.skipped_blueprints.push(bp.id);
+                    continue;
+                }
+                bp.save().await?;
+                installed.blueprints.push(bp.id.clone());
+                registry.insert(bp);
+            }
+        }
+
+        let area = Arc::new(RwLock::new(self.area));
+        let area_id = area.read().await.id().to_string();
+        for room in self.rooms {
+            let id = room.id().to_string();
+            let room = Arc::new(RwLock::new(room));
+            {
+                let mut lock = room.write().await;
+                lock.parent = Arc::downgrade(&area);
+                lock.save().await?;
+            }
+            area.write().await.rooms.insert(id.clone(), Arc::downgrade(&room));
+            world.rooms.insert(id, room);
+        }
+        area.write().await.save().await?;
+        world.areas.insert(area_id, area);
+        world.save_world_file()?;
+        Ok(installed)
+    }
+}
+
+#[cfg(test)]
+mod bundle_tests {
+    use std::collections::HashMap;
+
+    use crate::{item::blueprint::BlueprintKind, util::direction::Direction, world::exit::{Exit, state::ExitState}};
+
+    use super::*;
+
+    fn room(id: &str, exits: &[(Direction, &str)]) -> Room {
+        let mut r = Room::blank(Some(id));
+        r.parent_id = "forest".into();
+        r.exits = exits.iter()
+            .map(|(d, to)| (d.clone(), Exit { destination: (*to).into(), state: ExitState::AlwaysOpen, hidden: None }))
+            .collect::<HashMap<Direction, Exit>>();
+        r
+    }
+
+    fn bundle() -> AreaBundle {
+        let mut area = Area::blank();
+        area.id = "forest".into();
+        AreaBundle {
+            manifest: BundleManifest {
+                format: BUNDLE_FORMAT_VERSION,
+                area_id: "forest".into(),
+                room_ids: vec!["glade".into(), "path".into()],
+                blueprint_ids: vec![],
+                exported_at: 0,
+            },
+            area,
+            rooms: vec![
+                room("glade", &[(Direction::East, "path")]),
+                room("path", &[(Direction::West, "glade"), (Direction::North, "root")]),
+            ],
+            blueprints: vec![],
+        }
+    }
+
+    #[test]
+    fn remap_with_prefix() {
+        let mut b = bundle();
+        b.remap("stg-");
+        assert_eq!("stg-forest", b.area.id);
+        assert_eq!(vec!["stg-glade".to_string(), "stg-path".into()], b.manifest.room_ids);
+        let path = &b.rooms[1];
+        assert_eq!("stg-forest", path.parent_id);
+        assert_eq!("stg-glade", path.exits[&Direction::West].destination);
+        // exits leading out of the bundle stay put.
+        assert_eq!("root", path.exits[&Direction::North].destination);
+    }
+
+    #[test]
+    fn check_collisions_and_dangling() {
+        let mut w = World::blank();
+        w.rooms.insert("glade".into(), Arc::new(RwLock::new(Room::blank(Some("glade")))));
+        let issues = bundle().check(&w);
+        assert!(issues.contains(&BundleIssue::RoomCollision("glade".into())));
+        assert!(issues.contains(&BundleIssue::DanglingExit("path".into(), "root".into())));
+        assert!(issues.iter().any(|i| i.is_fatal()));
+
+        let mut b = bundle();
+        b.remap("stg-");
+        w.rooms.insert("root".into(), Arc::new(RwLock::new(Room::blank(Some("root")))));
+        assert!(b.check(&w).is_empty());
+    }
+
+    #[tokio::test]
+    async fn export_picks_keys_and_nested_items() {
+        let mut key = Blueprint::new("bundle-brass");
+        key.kind = BlueprintKind::Key;
+        BLUEPRINTS.write().await.insert(key);
+        let mut bag = Blueprint::new("bundle-bag");
+        bag.kind = BlueprintKind::Container;
+        bag.capacity = 2;
+        let mut coin = Blueprint::new("bundle-coin");
+        coin.kind = BlueprintKind::Tool;
+
+        let mut w = World::blank();
+        let b = bundle();
+        w.areas.insert("forest".into(), Arc::new(RwLock::new(b.area)));
+        let mut glade = room("glade", &[(Direction::East, "path")]);
+        glade.exits.get_mut(&Direction::East).unwrap().state = ExitState::Locked { key_id: "bundle-brass".into(), jam: None };
+        let mut bag = bag.instantiate();
+        assert!(bag.try_insert(coin.instantiate()).is_ok());
+        assert!(glade.contents.try_insert(bag).is_ok());
+        w.rooms.insert("glade".into(), Arc::new(RwLock::new(glade)));
+
+        let b = AreaBundle::export(&w, "forest").await.unwrap();
+        assert_eq!(vec!["bundle-bag".to_string(), "bundle-brass".into(), "bundle-coin".into()], b.manifest.blueprint_ids);
+        assert_eq!(BlueprintKind::Key, b.blueprints[1].kind);
+    }
+}
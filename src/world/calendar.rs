@@ -0,0 +1,104 @@
+//! Game calendar — hours, days, months and seasons, all derived from the
+//! [World][crate::world::World] uptime (which persists along with the world).
+use std::fmt::Display;
+
+use crate::game_loop::TICKS_PER_SECOND;
+
+/// Real-time seconds per game hour.
+const SECONDS_PER_HOUR: u64 = 60;
+pub(crate) const TICKS_PER_HOUR: u64 = SECONDS_PER_HOUR * TICKS_PER_SECOND;
+const HOURS_PER_DAY: u64 = 24;
+const DAYS_PER_MONTH: u64 = 30;
+const MONTHS: [&str; 12] = [
+    "Deepwinter", "Thawing", "Seedtime", "Blossom", "Greening", "Highsun",
+    "Summerflame", "Harvest", "Reaping", "Leaffall", "Frostfall", "Longnight",
+];
+
+pub(crate) const SUNRISE_HOUR: u64 = 6;
+pub(crate) const SUNSET_HOUR: u64 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    Winter, Spring, Summer, Autumn,
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Winter => "winter",
+            Self::Spring => "spring",
+            Self::Summer => "summer",
+            Self::Autumn => "autumn",
+        })
+    }
+}
+
+/// A point in game time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameTime {
+    pub year: u64,
+    /// 0-based month.
+    pub month: u64,
+    /// 1-based day of month.
+    pub day: u64,
+    pub hour: u64,
+}
+
+impl GameTime {
+    /// Game time at `uptime` ticks.
+    pub fn from_ticks(uptime: u64) -> Self {
+        let hours = uptime / TICKS_PER_HOUR;
+        let days = hours / HOURS_PER_DAY;
+        let months = days / DAYS_PER_MONTH;
+        Self {
+            year: 1 + months / MONTHS.len() as u64,
+            month: months % MONTHS.len() as u64,
+            day: 1 + days % DAYS_PER_MONTH,
+            hour: hours % HOURS_PER_DAY,
+        }
+    }
+
+    pub fn month_name(&self) -> &'static str {
+        MONTHS[self.month as usize]
+    }
+
+    pub fn season(&self) -> Season {
+        match self.month {
+            0 | 1 | 11 => Season::Winter,
+            2..=4 => Season::Spring,
+            5..=7 => Season::Summer,
+            _ => Season::Autumn,
+        }
+    }
+
+    pub fn is_daytime(&self) -> bool {
+        (SUNRISE_HOUR..SUNSET_HOUR).contains(&self.hour)
+    }
+}
+
+impl Display for GameTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:00, day {} of {}, year {} ({})",
+            self.hour, self.day, self.month_name(), self.year, self.season())
+    }
+}
+
+#[cfg(test)]
+mod calendar_tests {
+    use super::*;
+
+    #[test]
+    fn from_ticks() {
+        let t = GameTime::from_ticks(0);
+        assert_eq!((1, 0, 1, 0), (t.year, t.month, t.day, t.hour));
+        assert_eq!(Season::Winter, t.season());
+        assert!(!t.is_daytime());
+
+        let day = TICKS_PER_HOUR * HOURS_PER_DAY;
+        let t = GameTime::from_ticks(day * (DAYS_PER_MONTH * 12 + DAYS_PER_MONTH * 5 + 2) + TICKS_PER_HOUR * 13);
+        assert_eq!((2, 5, 3, 13), (t.year, t.month, t.day, t.hour));
+        assert_eq!(Season::Summer, t.season());
+        assert!(t.is_daytime());
+        assert_eq!("13:00, day 3 of Highsun, year 2 (summer)", t.to_string());
+    }
+}
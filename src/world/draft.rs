@@ -0,0 +1,264 @@
+//! Drafts — builder work that isn't out in the open (yet).
+//!
+//! Brand new [Room]s and [Area]s start out as drafts: builders see them,
+//! players don't, not even through exits leading there from live rooms.
+//! Edits of live rooms wait in a [RoomRevision] instead of going live as-is.
+//!
+//! A builder-lead (or admin) then [publishes][publish] the lot.
+use std::{collections::{HashMap, HashSet}, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{util::{direction::Direction, timestamp::unix_now}, world::{World, exit::Exit, room::{Room, flags::{RoomFlag, Sector}}}};
+
+/// Who drafted what, and when.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Draft {
+    pub author: String,
+    /// When the draft was (last) saved, UNIX seconds.
+    pub since: u64,
+}
+
+impl Draft {
+    /// A draft by `author`, as of right now.
+    pub fn new(author: &str) -> Self {
+        Self { author: author.into(), since: unix_now() }
+    }
+}
+
+/// Pending edits of a live [Room], waiting to be published.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct RoomRevision {
+    pub draft: Draft,
+    pub title: String,
+    pub description: String,
+    pub exits: HashMap<Direction, Exit>,
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub flags: HashSet<RoomFlag>,
+    #[serde(default)]
+    pub sector: Sector,
+}
+
+impl RoomRevision {
+    /// Capture the looks of `room` as a revision by `author`.
+    pub fn of(room: &Room, author: &str) -> Self {
+        Self {
+            draft: Draft::new(author),
+            title: room.title.clone(),
+            description: room.description.clone(),
+            exits: room.exits.clone(),
+            flags: room.flags.clone(),
+            sector: room.sector,
+        }
+    }
+
+    /// Make `room` look like the revision has it.
+    pub fn apply_to(&self, room: &mut Room) {
+        room.title = self.title.clone();
+        room.description = self.description.clone();
+        room.exits = self.exits.clone();
+        room.flags = self.flags.clone();
+        room.sector = self.sector;
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DraftError {
+    NotFound(String),
+    NothingPending(String),
+    /// The room's area is a draft itself — publish the area instead.
+    AreaIsDraft(String),
+}
+
+impl std::error::Error for DraftError {}
+impl Display for DraftError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(id) => write!(f, "No room or area called '{}'", id),
+            Self::NothingPending(id) => write!(f, "'{}' has nothing waiting to be published", id),
+            Self::AreaIsDraft(id) => write!(f, "Area '{}' is a draft as a whole; publish that instead", id),
+        }
+    }
+}
+
+/// What kind of thing a [PendingDraft] is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DraftKind {
+    Area,
+    Room,
+    Revision,
+}
+
+impl Display for DraftKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Area => "area",
+            Self::Room => "room",
+            Self::Revision => "revision",
+        })
+    }
+}
+
+/// An entry in the list of drafts waiting to be published.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingDraft {
+    pub kind: DraftKind,
+    pub id: String,
+    pub area_id: String,
+    pub draft: Draft,
+}
+
+/// List all pending drafts, oldest first.
+pub async fn pending(world: &World) -> Vec<PendingDraft> {
+    let mut list = vec![];
+    for (id, area) in &world.areas {
+        if let Some(draft) = &area.read().await.draft {
+            list.push(PendingDraft { kind: DraftKind::Area, id: id.clone(), area_id: id.clone(), draft: draft.clone() });
+        }
+    }
+    for (id, room) in &world.rooms {
+        let r = room.read().await;
+        if let Some(draft) = &r.draft {
+            list.push(PendingDraft { kind: DraftKind::Room, id: id.clone(), area_id: r.parent_id.clone(), draft: draft.clone() });
+        }
+        if let Some(rev) = &r.revision {
+            list.push(PendingDraft { kind: DraftKind::Revision, id: id.clone(), area_id: r.parent_id.clone(), draft: rev.draft.clone() });
+        }
+    }
+    list.sort_by(|a, b| a.draft.since.cmp(&b.draft.since).then_with(|| a.id.cmp(&b.id)));
+    list
+}
+
+/// What got published.
+#[derive(Debug, Default, PartialEq)]
+pub struct Published {
+    /// The area, if it was published as a whole.
+    pub area: Option<String>,
+    /// Rooms which went live or got their revisions applied, sorted.
+    pub rooms: Vec<String>,
+}
+
+/// Publish area `id` along with all the drafts within.
+///
+/// Takes the (write-locked) world, so nobody gets to see a half-published
+/// state. Persisting the changes is up to the caller.
+pub async fn publish_area(world: &mut World, id: &str) -> Result<Published, DraftError> {
+    let Some(area) = world.areas.get(id) else {
+        return Err(DraftError::NotFound(id.into()));
+    };
+    let mut published = Published::default();
+    let was_draft = area.write().await.draft.take().is_some();
+    for (room_id, room) in &world.rooms {
+        let mut r = room.write().await;
+        if r.parent_id == id && go_live(&mut r) {
+            published.rooms.push(room_id.clone());
+        }
+    }
+    if !was_draft && published.rooms.is_empty() {
+        return Err(DraftError::NothingPending(id.into()));
+    }
+    if was_draft {
+        published.area = Some(id.into());
+    }
+    published.rooms.sort();
+    Ok(published)
+}
+
+/// Publish room `id` — as long as its area isn't a draft as a whole.
+///
+/// See [publish_area] re. locking and persisting.
+pub async fn publish_room(world: &mut World, id: &str) -> Result<Published, DraftError> {
+    let Some(room) = world.rooms.get(id) else {
+        return Err(DraftError::NotFound(id.into()));
+    };
+    let mut r = room.write().await;
+    let area_is_draft = match world.areas.get(&r.parent_id) {
+        Some(area) => area.read().await.draft.is_some(),
+        None => false
+    };
+    if area_is_draft {
+        return Err(DraftError::AreaIsDraft(r.parent_id.clone()));
+    }
+    if !go_live(&mut r) {
+        return Err(DraftError::NothingPending(id.into()));
+    }
+    Ok(Published { area: None, rooms: vec![id.into()] })
+}
+
+/// Put `room` live, revision and all.
+///
+/// # Returns
+/// `true` if there was anything to publish.
+fn go_live(room: &mut Room) -> bool {
+    let was_draft = room.draft.take().is_some();
+    match room.revision.take() {
+        Some(rev) => { rev.apply_to(room); true },
+        None => was_draft
+    }
+}
+
+#[cfg(test)]
+mod draft_tests {
+    use std::sync::Arc;
+
+    use tokio::sync::RwLock;
+
+    use crate::world::area::Area;
+
+    use super::*;
+
+    async fn world() -> World {
+        let mut w = World::blank();
+        for (area_id, draft) in [("live", false), ("fresh", true)] {
+            let mut a = Area::new(area_id);
+            if draft {
+                a.draft = Some(Draft::new("bob"));
+            }
+            w.areas.insert(area_id.into(), Arc::new(RwLock::new(a)));
+        }
+        for (id, area_id, draft) in [("plaza", "live", false), ("annex", "live", true), ("hut", "fresh", false)] {
+            let mut r = Room::blank(Some(id));
+            r.parent_id = area_id.into();
+            if draft {
+                r.draft = Some(Draft::new("alice"));
+            }
+            w.rooms.insert(id.into(), Arc::new(RwLock::new(r)));
+        }
+        w
+    }
+
+    #[tokio::test]
+    async fn revision_applies_on_publish() {
+        let mut w = world().await;
+        {
+            let mut edited = w.rooms["plaza"].read().await.clone();
+            edited.title = "The Plaza".into();
+            w.rooms["plaza"].write().await.revision = Some(RoomRevision::of(&edited, "alice"));
+        }
+        assert!(w.is_draft("annex").await);
+        assert!(w.is_draft("hut").await);
+        assert!(!w.is_draft("plaza").await);
+        assert_eq!(3, pending(&w).await.len());
+
+        let p = publish_room(&mut w, "plaza").await.unwrap();
+        assert_eq!(vec!["plaza".to_string()], p.rooms);
+        let plaza = w.rooms["plaza"].read().await;
+        assert_eq!("The Plaza", plaza.title);
+        assert!(plaza.revision.is_none());
+    }
+
+    #[tokio::test]
+    async fn area_publishes_as_a_whole() {
+        let mut w = world().await;
+        assert_eq!(Err(DraftError::AreaIsDraft("fresh".into())), publish_room(&mut w, "hut").await);
+        let p = publish_area(&mut w, "fresh").await.unwrap();
+        assert_eq!(Some("fresh".to_string()), p.area);
+        assert!(!w.is_draft("hut").await);
+        assert_eq!(Err(DraftError::NothingPending("fresh".into())), publish_area(&mut w, "fresh").await);
+
+        let p = publish_area(&mut w, "live").await.unwrap();
+        assert_eq!((None, vec!["annex".to_string()]), (p.area, p.rooms));
+        assert!(pending(&w).await.is_empty());
+        assert_eq!(Err(DraftError::NotFound("nowhere".into())), publish_room(&mut w, "nowhere").await);
+    }
+}
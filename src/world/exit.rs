@@ -8,7 +8,11 @@ pub mod state;
 pub mod jam;
 use state::*;
 
-use crate::world::exit::jam::JamState;
+use crate::{player::Player, util::direction::Direction, world::exit::jam::JamState};
+
+const DEFAULT_DISCOVERY_DIFFICULTY: u8 = 50;
+
+const fn default_discovery_difficulty() -> u8 {DEFAULT_DISCOVERY_DIFFICULTY}
 
 /// All sorts of key related errors…
 #[derive(Debug, Clone, Copy)]
@@ -21,11 +25,50 @@ pub enum KeyError {
     Jammed,
 }
 
+/// How well an [Exit] is tucked away from plain sight.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Concealment {
+    /// Secret exits can't be found by searching — they have to be learned of.
+    #[serde(default)]
+    pub secret: bool,
+    /// How hard (0-100) it is to spot by searching.
+    #[serde(default = "default_discovery_difficulty")]
+    pub difficulty: u8,
+    /// What the discoverer is told, if not the default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl Default for Concealment {
+    fn default() -> Self {
+        Self { secret: false, difficulty: DEFAULT_DISCOVERY_DIFFICULTY, message: None }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Exit {
     pub destination: String,
     #[serde(default)]
     pub state: ExitState,
+    /// Hidden or secret, if at all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<Concealment>,
+}
+
+/// Secret ID of the exit at `room_id` towards `dir`, as known by [Player]s.
+pub fn secret_id(room_id: &str, dir: &Direction) -> String {
+    format!("exit:{}:{}", room_id, dir.as_key())
+}
+
+impl Exit {
+    /// Can `player` see (and use) the exit at `room_id` towards `dir`?
+    ///
+    /// Builders see through all the smoke and mirrors.
+    pub fn visible_to(&self, room_id: &str, dir: &Direction, player: &Player) -> bool {
+        self.hidden.is_none()
+        || player.access.is_builder()
+        || player.knows(&secret_id(room_id, dir))
+    }
 }
 
 impl PartialEq for Exit {
@@ -45,6 +88,7 @@ impl From<&str> for Exit {
         Self {
             destination: destination.into(),
             state: ExitState::default(),
+            hidden: None,
         }
     }
 }
@@ -82,9 +126,35 @@ mod room_exit_tests {
         let _ = env_logger::try_init();
         let exit = Exit {
             destination: "nowhere-much".into(),
-            state: ExitState::Open { key_id: None }
+            state: ExitState::Open { key_id: None },
+            hidden: None,
         };
         let json = serde_json::to_string_pretty(&exit).unwrap();
         log::debug!("JSON: {json}");
     }
+
+    #[test]
+    fn door_configuration() {
+        let locked = ExitState::configured(DoorKind::Locked, Some("key".into()), None).unwrap();
+        assert_eq!(ExitState::Locked { key_id: "key".into(), jam: None }, locked);
+        assert_eq!(DoorKind::Locked, locked.door());
+        assert_eq!(Some("key"), locked.key());
+        assert_eq!(Err(DoorError::NeedsKey), ExitState::configured(DoorKind::Locked, None, None));
+        assert_eq!(Err(DoorError::NoDoor), ExitState::configured(DoorKind::None, Some("key".into()), None));
+        let jam = Some(JamState::WholeExit(crate::world::exit::jam::JammingMethod::Barred));
+        assert_eq!(Err(DoorError::JamWhileOpen), ExitState::configured(DoorKind::Open, None, jam));
+        assert_eq!(Some(JamState::WholeExit(crate::world::exit::jam::JammingMethod::Barred)),
+            ExitState::configured(DoorKind::Closed, None, jam).unwrap().is_jammed());
+    }
+
+    #[test]
+    fn hidden_exit_visibility() {
+        let exit: Exit = serde_json::from_str(r#"{"destination": "cellar", "hidden": {"difficulty": 30}}"#).unwrap();
+        assert_eq!(30, exit.hidden.as_ref().unwrap().difficulty);
+        let mut p = Player::new("seeker");
+        assert!(!exit.visible_to("pantry", &Direction::Down, &p));
+        p.learn(&secret_id("pantry", &Direction::Down), "found by searching");
+        assert!(exit.visible_to("pantry", &Direction::Down, &p));
+        assert!(!exit.visible_to("pantry", &Direction::Up, &p));
+    }
 }
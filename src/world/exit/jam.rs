@@ -11,6 +11,26 @@ pub enum JammingMethod {
     Vault
 }
 
+impl JammingMethod {
+    pub const ALL: [JammingMethod; 4] = [Self::Sabotaged, Self::Barred, Self::Welded, Self::Vault];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sabotaged => "sabotaged",
+            Self::Barred => "barred",
+            Self::Welded => "welded",
+            Self::Vault => "vault",
+        }
+    }
+}
+
+impl TryFrom<&str> for JammingMethod {
+    type Error = ();
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::ALL.into_iter().find(|m| m.as_str() == value.to_lowercase()).ok_or(())
+    }
+}
+
 /// State of jam.
 // Edible isn't one of the options…
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -164,6 +164,109 @@ impl ExitState {
     }
 }
 
+/// Kinds of doors (or lack thereof), as builders see them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DoorKind {
+    /// No door at all; see [ExitState::AlwaysOpen].
+    None,
+    Open,
+    Closed,
+    Locked,
+}
+
+impl DoorKind {
+    pub const ALL: [DoorKind; 4] = [Self::None, Self::Open, Self::Closed, Self::Locked];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Open => "open",
+            Self::Closed => "closed",
+            Self::Locked => "locked",
+        }
+    }
+}
+
+impl Display for DoorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for DoorKind {
+    type Error = ();
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::ALL.into_iter().find(|k| k.as_str() == value.to_lowercase()).ok_or(())
+    }
+}
+
+/// Reasons an [ExitState] can't be configured as asked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DoorError {
+    /// Locked doors need a key.
+    NeedsKey,
+    /// Keys and jams need a door to go with.
+    NoDoor,
+    /// Open doors can't be jammed.
+    JamWhileOpen,
+}
+
+impl Display for DoorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::NeedsKey => "A locked door needs a key",
+            Self::NoDoor => "There's no door to begin with",
+            Self::JamWhileOpen => "An open door can't be jammed",
+        })
+    }
+}
+
+impl ExitState {
+    /// Put together an [ExitState] out of its bits and pieces.
+    pub fn configured(kind: DoorKind, key_id: Option<String>, jam: Option<JamState>) -> Result<Self, DoorError> {
+        Ok(match kind {
+            DoorKind::None if key_id.is_some() || jam.is_some() => return Err(DoorError::NoDoor),
+            DoorKind::None => Self::AlwaysOpen,
+            DoorKind::Open if jam.is_some() => return Err(DoorError::JamWhileOpen),
+            DoorKind::Open => Self::Open { key_id },
+            DoorKind::Closed => Self::Closed { key_id, jam },
+            DoorKind::Locked => Self::Locked { key_id: key_id.ok_or(DoorError::NeedsKey)?, jam },
+        })
+    }
+
+    /// What kind of a door there is.
+    pub fn door(&self) -> DoorKind {
+        match self {
+            Self::AlwaysOpen => DoorKind::None,
+            Self::Open {..} => DoorKind::Open,
+            Self::Closed {..} => DoorKind::Closed,
+            Self::Locked {..} => DoorKind::Locked,
+        }
+    }
+
+    /// ID of the key, if there's a lock at all.
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            Self::AlwaysOpen => None,
+            Self::Open { key_id } |
+            Self::Closed { key_id, .. } => key_id.as_deref(),
+            Self::Locked { key_id, .. } => Some(key_id),
+        }
+    }
+
+    /// Builder's view of the door, key and jam.
+    pub fn describe(&self) -> String {
+        let mut out = self.door().to_string();
+        if let Some(key) = self.key() {
+            out.push_str(&format!(", key: {}", key));
+        }
+        if let Some(jam) = self.is_jammed() {
+            out.push_str(&format!(", jam: {:?}", jam));
+        }
+        out
+    }
+}
+
 /// Equalize state of the opposite end of `$exit`.
 /// 
 /// # Args
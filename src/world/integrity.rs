@@ -0,0 +1,230 @@
+//! World integrity checks — dangling exits, one-way passages, mismatched
+//! doors, unreachable rooms, keys nobody can get hold of, and so on.
+//!
+//! Run at startup (as log warnings) and on demand via `wcheck`.
+use std::{collections::{HashMap, HashSet, VecDeque}, fmt::Display};
+
+use crate::{item::{Item, ItemMap, blueprint::{BLUEPRINTS, BlueprintKind}, inventory::Storage}, traits::IdentityQuery, world::{World, exit::state::{ExitState, ExitStateQuery}}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IssueKind {
+    /// Exit leads to a room that doesn't exist.
+    MissingRoom,
+    /// Exit doesn't lead back the way it came.
+    OneWay,
+    /// Door is e.g. open on one side but locked on the other.
+    DoorMismatch,
+    /// No way to walk there from the root room.
+    Unreachable,
+    /// Locked with a key that has no blueprint.
+    UnknownKey,
+    /// Same ID used for more than one thing.
+    DuplicateId,
+    /// Room's parent area doesn't exist.
+    Orphan,
+}
+
+impl IssueKind {
+    pub const ALL: [Self; 7] = [
+        Self::MissingRoom, Self::OneWay, Self::DoorMismatch, Self::Unreachable,
+        Self::UnknownKey, Self::DuplicateId, Self::Orphan
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MissingRoom => "missing",
+            Self::OneWay => "oneway",
+            Self::DoorMismatch => "door",
+            Self::Unreachable => "unreachable",
+            Self::UnknownKey => "key",
+            Self::DuplicateId => "duplicate",
+            Self::Orphan => "orphan",
+        }
+    }
+}
+
+impl Display for IssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A single finding.
+#[derive(Debug, Clone)]
+pub struct Issue {
+    pub kind: IssueKind,
+    /// Room the issue is about.
+    pub room_id: String,
+    /// Area of the room, if known.
+    pub area_id: String,
+    pub detail: String,
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}:{} — {}", self.kind, self.area_id, self.room_id, self.detail)
+    }
+}
+
+/// Gather all items within `items`, containers' contents included.
+pub(crate) fn walk_items<'a>(items: &'a ItemMap, out: &mut Vec<&'a Item>) {
+    for item in items.values() {
+        out.push(item);
+        if let Item::Container(c) = item {
+            walk_items(c.items(), out);
+        }
+    }
+}
+
+/// Run all the checks over `world`.
+///
+/// # Returns
+/// Whatever was found, sorted by kind and room.
+pub async fn check(world: &World) -> Vec<Issue> {
+    let mut issues = vec![];
+    let mut areas_of: HashMap<String, String> = HashMap::new();
+    // room → [(dir, destination, state)]
+    let mut exits = HashMap::new();
+    let key_blueprints = BLUEPRINTS.read().await.list().iter()
+        .filter(|bp| bp.kind == BlueprintKind::Key)
+        .map(|bp| bp.id.clone())
+        .collect::<HashSet<_>>();
+    // item ID → room IDs it's found in
+    let mut item_ids: HashMap<String, Vec<String>> = HashMap::new();
+
+    for (id, area) in &world.areas {
+        let a = area.read().await;
+        if a.id != *id {
+            issues.push(Issue { kind: IssueKind::DuplicateId, room_id: "-".into(), area_id: id.clone(),
+                detail: format!("area file '{}' claims ID '{}'", id, a.id) });
+        }
+    }
+
+    for (id, room) in &world.rooms {
+        let r = room.read().await;
+        areas_of.insert(id.clone(), r.parent_id.clone());
+        if r.id != *id {
+            issues.push(Issue { kind: IssueKind::DuplicateId, room_id: id.clone(), area_id: r.parent_id.clone(),
+                detail: format!("room file '{}' claims ID '{}'", id, r.id) });
+        }
+        if !world.areas.contains_key(&r.parent_id) {
+            issues.push(Issue { kind: IssueKind::Orphan, room_id: id.clone(), area_id: r.parent_id.clone(),
+                detail: format!("parent area '{}' does not exist", r.parent_id) });
+        }
+        let mut items = vec![];
+        walk_items(r.contents.items(), &mut items);
+        for item in items {
+            item_ids.entry(item.id().to_string()).or_default().push(id.clone());
+        }
+        exits.insert(id.clone(), r.exits.iter()
+            .map(|(d, e)| (d.clone(), e.destination.clone(), e.state.clone()))
+            .collect::<Vec<_>>());
+    }
+
+    let area_of = |id: &str| areas_of.get(id).cloned().unwrap_or_else(|| "?".into());
+
+    for (id, list) in &exits {
+        for (dir, dest, state) in list {
+            if state.is_locked() && !key_blueprints.contains(state.key_id()) {
+                issues.push(Issue { kind: IssueKind::UnknownKey, room_id: id.clone(), area_id: area_of(id),
+                    detail: format!("{} is locked with '{}', but there's no such key blueprint", dir, state.key_id()) });
+            }
+            let Some(back) = exits.get(dest) else {
+                issues.push(Issue { kind: IssueKind::MissingRoom, room_id: id.clone(), area_id: area_of(id),
+                    detail: format!("{} leads to non-existent '{}'", dir, dest) });
+                continue;
+            };
+            let way_back = match dir.opposite() {
+                Ok(opp) => back.iter().find(|(d, to, _)| *d == opp && to == id),
+                Err(_) => back.iter().find(|(_, to, _)| to == id),
+            };
+            let Some((back_dir, _, back_state)) = way_back else {
+                issues.push(Issue { kind: IssueKind::OneWay, room_id: id.clone(), area_id: area_of(id),
+                    detail: format!("{} to '{}' has no way back", dir, dest) });
+                continue;
+            };
+            // report each door only once, from the "smaller" side.
+            if id < dest && !same_door_state(state, back_state) {
+                issues.push(Issue { kind: IssueKind::DoorMismatch, room_id: id.clone(), area_id: area_of(id),
+                    detail: format!("{} is {} but {} from '{}' is {}", dir, state, back_dir, dest, back_state) });
+            }
+        }
+    }
+
+    for (item_id, rooms) in item_ids.iter().filter(|(_, r)| r.len() > 1) {
+        issues.push(Issue { kind: IssueKind::DuplicateId, room_id: rooms[0].clone(), area_id: area_of(&rooms[0]),
+            detail: format!("item '{}' exists in {} places: {}", item_id, rooms.len(), rooms.join(", ")) });
+    }
+
+    // finally, what can be reached from the root?
+    let mut reached = HashSet::from([world.root.room.clone()]);
+    let mut queue = VecDeque::from([world.root.room.clone()]);
+    while let Some(id) = queue.pop_front() {
+        for (_, dest, _) in exits.get(&id).into_iter().flatten() {
+            if exits.contains_key(dest) && reached.insert(dest.clone()) {
+                queue.push_back(dest.clone());
+            }
+        }
+    }
+    for id in exits.keys().filter(|id| !reached.contains(*id)) {
+        issues.push(Issue { kind: IssueKind::Unreachable, room_id: id.clone(), area_id: area_of(id),
+            detail: format!("no route from '{}'", world.root.room) });
+    }
+
+    issues.sort_by(|a, b| (a.kind, &a.room_id, &a.detail).cmp(&(b.kind, &b.room_id, &b.detail)));
+    issues
+}
+
+/// Are both sides of a door in the same shape?
+fn same_door_state(a: &ExitState, b: &ExitState) -> bool {
+    a.is_open() == b.is_open()
+    && a.is_locked() == b.is_locked()
+    && a.key_id() == b.key_id()
+}
+
+#[cfg(test)]
+mod integrity_tests {
+    use std::sync::Arc;
+
+    use tokio::sync::RwLock;
+
+    use crate::{item::blueprint::Blueprint, util::direction::Direction, world::{area::Area, exit::Exit, room::Room}};
+
+    use super::*;
+
+    fn room(w: &mut World, id: &str, exits: &[(Direction, &str, ExitState)]) {
+        let mut r = Room::blank(Some(id));
+        for (d, dest, state) in exits {
+            r.exits.insert(d.clone(), Exit { destination: dest.to_string(), state: state.clone(), hidden: None });
+        }
+        w.rooms.insert(id.into(), Arc::new(RwLock::new(r)));
+    }
+
+    #[tokio::test]
+    async fn finds_issues() {
+        let open = ExitState::Open { key_id: None };
+        let locked = ExitState::Locked { key_id: "brass".into(), jam: None };
+        let vault = ExitState::Locked { key_id: "integrity-iron".into(), jam: None };
+        let mut key = Blueprint::new("integrity-iron");
+        key.kind = BlueprintKind::Key;
+        BLUEPRINTS.write().await.insert(key);
+        let mut w = World::blank();
+        let mut area = Area::blank();
+        area.id = "root".into();
+        w.areas.insert("root".into(), Arc::new(RwLock::new(area)));
+        w.root.room = "root".into();
+        room(&mut w, "root", &[(Direction::East, "hall", open.clone()), (Direction::North, "nowhere", open.clone()), (Direction::South, "vault", vault.clone())]);
+        room(&mut w, "vault", &[(Direction::North, "root", vault)]);
+        room(&mut w, "hall", &[(Direction::West, "root", locked), (Direction::Up, "root", open.clone())]);
+        room(&mut w, "island", &[]);
+
+        let kinds = check(&w).await.iter().map(|i| (i.kind, i.room_id.clone())).collect::<Vec<_>>();
+        assert_eq!(vec![
+            (IssueKind::MissingRoom, "root".into()),
+            (IssueKind::OneWay, "hall".into()),
+            (IssueKind::DoorMismatch, "hall".into()),
+            (IssueKind::Unreachable, "island".into()),
+            (IssueKind::UnknownKey, "hall".into()),
+        ], kinds);
+    }
+}
@@ -0,0 +1,206 @@
+//! ASCII minimap of the rooms around.
+//!
+//! Coordinates are inferred from the cardinal (and diagonal) exits, starting
+//! from the center room. Whatever doesn't fit a flat grid — overlaps, loops
+//! that don't add up, etc. — is drawn as a `?` passage instead of a room.
+//!
+//! ```text
+//! [ ]-[ ]
+//!  |   +
+//! [@]-[^]
+//! ```
+use std::collections::{HashMap, VecDeque};
+
+use crate::{player::Player, util::direction::Direction, world::{World, exit::state::ExitStateQuery}};
+
+/// Default radius (in rooms) of the map.
+pub const MINIMAP_RADIUS: i32 = 3;
+
+const CELL_W: i32 = 4;// "[ ]" + connector column.
+const CELL_H: i32 = 2;// room row + connector row.
+
+/// Grid offset of a cardinal/diagonal [Direction], if it's one.
+fn offset(dir: &Direction) -> Option<(i32, i32)> {
+    Some(match dir {
+        Direction::North => (0, -1),
+        Direction::South => (0, 1),
+        Direction::East => (1, 0),
+        Direction::West => (-1, 0),
+        Direction::NorthEast => (1, -1),
+        Direction::NorthWest => (-1, -1),
+        Direction::SouthEast => (1, 1),
+        Direction::SouthWest => (-1, 1),
+        _ => return None
+    })
+}
+
+/// What a room looks like on the map.
+fn marker(is_center: bool, up: bool, down: bool, custom: bool) -> char {
+    match (is_center, up, down, custom) {
+        (true, ..) => '@',
+        (_, true, true, _) => '%',
+        (_, true, _, _) => '^',
+        (_, _, true, _) => 'v',
+        (_, _, _, true) => '*',
+        _ => ' '
+    }
+}
+
+struct Canvas {
+    radius: i32,
+    rows: Vec<Vec<char>>,
+}
+
+impl Canvas {
+    fn new(radius: i32) -> Self {
+        let w = (2 * radius + 1) * CELL_W - 1;
+        let h = (2 * radius + 1) * CELL_H - 1;
+        Self { radius, rows: vec![vec![' '; w as usize]; h as usize] }
+    }
+
+    /// Put `c` at cell-relative position; `(x, y)` in room coordinates.
+    fn put(&mut self, (x, y): (i32, i32), (dx, dy): (i32, i32), c: char) {
+        let col = (x + self.radius) * CELL_W + dx;
+        let row = (y + self.radius) * CELL_H + dy;
+        if let Some(slot) = self.rows.get_mut(row as usize).and_then(|r| r.get_mut(col as usize)) {
+            // crossing diagonals…
+            *slot = match (*slot, c) {
+                ('/', '\\') | ('\\', '/') => 'X',
+                _ => c
+            };
+        }
+    }
+
+    fn room(&mut self, at: (i32, i32), mark: char) {
+        self.put(at, (0, 0), '[');
+        self.put(at, (1, 0), mark);
+        self.put(at, (2, 0), ']');
+    }
+
+    /// Draw a passage from `at` towards `(ox, oy)`.
+    fn passage(&mut self, at: (i32, i32), (ox, oy): (i32, i32), c: Option<char>) {
+        let (slot, default) = match (ox, oy) {
+            (1, 0)   => ((3, 0), '-'),
+            (-1, 0)  => ((-1, 0), '-'),
+            (0, 1)   => ((1, 1), '|'),
+            (0, -1)  => ((1, -1), '|'),
+            (1, 1)   => ((3, 1), '\\'),
+            (-1, -1) => ((-1, -1), '\\'),
+            (1, -1)  => ((3, -1), '/'),
+            (-1, 1)  => ((-1, 1), '/'),
+            _ => return
+        };
+        self.put(at, slot, c.unwrap_or(default));
+    }
+
+    /// Stringify, sans the blank rows above and below.
+    fn render(self) -> String {
+        let lines = self.rows.into_iter()
+            .map(|r| r.into_iter().collect::<String>().trim_end().to_string())
+            .collect::<Vec<_>>();
+        let first = lines.iter().position(|l| !l.is_empty()).unwrap_or(0);
+        let last = lines.iter().rposition(|l| !l.is_empty()).unwrap_or(0);
+        let mut out = String::new();
+        for line in &lines[first..=last] {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Render a map of `radius` rooms around `center`.
+///
+/// Hidden exits show only if `viewer` can see them (or if there's no `viewer`).
+///
+/// # Returns
+/// The map, or `None` if `center` doesn't exist.
+pub async fn render(world: &World, center: &str, radius: i32, viewer: Option<&Player>) -> Option<String> {
+    world.rooms.get(center)?;
+
+    let mut placed: HashMap<String, (i32, i32)> = HashMap::from([(center.to_string(), (0, 0))]);
+    let mut taken: HashMap<(i32, i32), String> = HashMap::from([((0, 0), center.to_string())]);
+    let mut queue = VecDeque::from([center.to_string()]);
+    let mut canvas = Canvas::new(radius);
+
+    while let Some(id) = queue.pop_front() {
+        let Some(room) = world.rooms.get(&id) else { continue };
+        let room = room.read().await;
+        let at = placed[&id];
+        let (mut up, mut down, mut custom) = (false, false, false);
+
+        // sorted, so that conflicts resolve the same way every time.
+        let mut exits = room.exits.iter().collect::<Vec<_>>();
+        exits.sort_by_key(|(d, _)| d.to_string());
+        for (dir, exit) in exits {
+            if viewer.is_some_and(|p| !exit.visible_to(&id, dir, p)) {
+                continue;
+            }
+            // drafts are nobody's business but builders'.
+            if viewer.is_some_and(|p| !p.access.is_builder())
+            && exit.destination != id && world.is_draft(&exit.destination).await {
+                continue;
+            }
+            let Some(off) = offset(dir) else {
+                match dir {
+                    Direction::Up => up = true,
+                    Direction::Down => down = true,
+                    _ => custom = true
+                }
+                continue;
+            };
+            let there = (at.0 + off.0, at.1 + off.1);
+            let door = if exit.is_closed() { Some('+') } else { None };
+            let fits = match (placed.get(&exit.destination), taken.get(&there)) {
+                // already on the map, right where it should be.
+                (Some(p), _) => *p == there,
+                // something else already sits there.
+                (None, Some(_)) => false,
+                (None, None) => {
+                    if there.0.abs() <= radius && there.1.abs() <= radius && world.rooms.contains_key(&exit.destination) {
+                        placed.insert(exit.destination.clone(), there);
+                        taken.insert(there, exit.destination.clone());
+                        queue.push_back(exit.destination.clone());
+                    }
+                    true
+                }
+            };
+            canvas.passage(at, off, if fits { door } else { Some('?') });
+        }
+        canvas.room(at, marker(id == center, up, down, custom));
+    }
+
+    Some(canvas.render())
+}
+
+#[cfg(test)]
+mod minimap_tests {
+    use std::sync::Arc;
+
+    use tokio::sync::RwLock;
+
+    use crate::world::{exit::{Exit, state::ExitState}, room::Room};
+
+    use super::*;
+
+    fn room(w: &mut World, id: &str, exits: &[(Direction, &str, bool)]) {
+        let mut r = Room::blank(Some(id));
+        for (d, dest, closed) in exits {
+            let state = if *closed { ExitState::Closed { key_id: None, jam: None } } else { ExitState::Open { key_id: None } };
+            r.exits.insert(d.clone(), Exit { destination: dest.to_string(), state, hidden: None });
+        }
+        w.rooms.insert(id.into(), Arc::new(RwLock::new(r)));
+    }
+
+    #[tokio::test]
+    async fn draw() {
+        let mut w = World::blank();
+        room(&mut w, "a", &[(Direction::East, "b", false), (Direction::North, "c", true)]);
+        room(&mut w, "b", &[(Direction::West, "a", false), (Direction::Up, "x", false)]);
+        // c claims 'a' is to its east — which doesn't add up.
+        room(&mut w, "c", &[(Direction::South, "a", true), (Direction::East, "a", false)]);
+        let map = render(&w, "a", 1, None).await.unwrap();
+        assert_eq!("    [ ]?\n     +\n    [@]-[^]\n", map);
+        assert!(render(&w, "nowhere", 1, None).await.is_none());
+    }
+}
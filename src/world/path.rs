@@ -0,0 +1,173 @@
+//! Pathfinding — shortest routes between [Room]s.
+//!
+//! Plain BFS over [Room::exits], much like [find_nearby_rooms][crate::world::room::find_nearby_rooms]
+//! does, but with [ExitState]s and area boundaries taken into account.
+use std::{collections::{HashMap, HashSet, VecDeque}, fmt::Display};
+
+use crate::{util::direction::Direction, world::{World, exit::{secret_id, state::{ExitState, ExitStateQuery}}, room::Room}};
+
+/// What's passable and what's not.
+#[derive(Debug, Clone, Default)]
+pub struct PathOptions {
+    /// Route through closed (but not locked) exits?
+    pub through_closed: bool,
+    /// Route through locked exits?
+    pub through_locked: bool,
+    /// Route through hidden/secret exits?
+    pub through_hidden: bool,
+    /// [Secret IDs][secret_id] of hidden exits one knows of, and may thus route through regardless.
+    pub known_exits: HashSet<String>,
+    /// Stay within these areas, if set.
+    pub areas: Option<HashSet<String>>,
+    /// Route through draft rooms?
+    pub through_drafts: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PathError {
+    NoSuchRoom(String),
+    NoRoute,
+}
+
+impl std::error::Error for PathError {}
+impl Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSuchRoom(id) => write!(f, "No such room as '{}'", id),
+            Self::NoRoute => write!(f, "No route found"),
+        }
+    }
+}
+
+impl PathOptions {
+    /// Can one pass through an exit in `state`?
+    fn passable(&self, state: &ExitState) -> bool {
+        match state {
+            ExitState::AlwaysOpen => true,
+            s if s.is_locked() => self.through_locked,
+            s if s.is_closed() => self.through_closed,
+            _ => true
+        }
+    }
+
+    /// Is `room` within the allowed areas?
+    fn allows(&self, room: &Room) -> bool {
+        self.areas.as_ref().is_none_or(|a| a.contains(&room.parent_id))
+    }
+}
+
+/// Find the shortest route from `from` to `to`.
+///
+/// # Returns
+/// Sequence of [Direction]s to follow — empty if `from` ≡ `to`.
+pub async fn find_path(world: &World, from: &str, to: &str, opts: &PathOptions) -> Result<Vec<Direction>, PathError> {
+    for id in [from, to] {
+        if !world.rooms.contains_key(id) {
+            return Err(PathError::NoSuchRoom(id.into()));
+        }
+    }
+    if from == to {
+        return Ok(vec![]);
+    }
+
+    // room → (came from, via)
+    let mut came_from: HashMap<String, (String, Direction)> = HashMap::new();
+    let mut visited = HashSet::from([from.to_string()]);
+    let mut queue = VecDeque::from([from.to_string()]);
+
+    while let Some(current) = queue.pop_front() {
+        let Some(room) = world.rooms.get(&current) else { continue };
+        let room = room.read().await;
+        // sorted, so that equally short routes come out the same every time.
+        let mut exits = room.exits.iter().collect::<Vec<_>>();
+        exits.sort_by_key(|(d, _)| d.to_string());
+        for (dir, exit) in exits {
+            if visited.contains(&exit.destination) || !opts.passable(&exit.state)
+            || (exit.hidden.is_some() && !opts.through_hidden && !opts.known_exits.contains(&secret_id(&current, dir))) {
+                continue;
+            }
+            let Some(next) = world.rooms.get(&exit.destination) else { continue };
+            {
+                let next = next.read().await;
+                if !opts.allows(&next) || (!opts.through_drafts && world.is_draft_room(&next).await) {
+                    continue;
+                }
+            }
+            visited.insert(exit.destination.clone());
+            came_from.insert(exit.destination.clone(), (current.clone(), dir.clone()));
+            if exit.destination == to {
+                return Ok(unwind(&came_from, to));
+            }
+            queue.push_back(exit.destination.clone());
+        }
+    }
+
+    Err(PathError::NoRoute)
+}
+
+/// Walk the breadcrumbs back from `to` and flip them around.
+fn unwind(came_from: &HashMap<String, (String, Direction)>, to: &str) -> Vec<Direction> {
+    let mut dirs = vec![];
+    let mut at = to;
+    while let Some((prev, dir)) = came_from.get(at) {
+        dirs.push(dir.clone());
+        at = prev;
+    }
+    dirs.reverse();
+    dirs
+}
+
+#[cfg(test)]
+mod path_tests {
+    use std::sync::Arc;
+
+    use tokio::sync::RwLock;
+
+    use crate::world::exit::Exit;
+
+    use super::*;
+
+    fn room(id: &str, area: &str, exits: &[(Direction, &str, ExitState)]) -> Arc<RwLock<Room>> {
+        let mut r = Room::blank(Some(id));
+        r.parent_id = area.into();
+        for (d, dest, state) in exits {
+            r.exits.insert(d.clone(), Exit { destination: dest.to_string(), state: state.clone(), hidden: None });
+        }
+        Arc::new(RwLock::new(r))
+    }
+
+    #[tokio::test]
+    async fn routes() {
+        let open = ExitState::Open { key_id: None };
+        let closed = ExitState::Closed { key_id: None, jam: None };
+        let mut w = World::blank();
+        // a -e-> b -n(closed)-> c ; a -s-> d(other area) -e-> c
+        w.rooms.insert("a".into(), room("a", "x", &[(Direction::East, "b", open.clone()), (Direction::South, "d", open.clone())]));
+        w.rooms.insert("b".into(), room("b", "x", &[(Direction::North, "c", closed)]));
+        w.rooms.insert("c".into(), room("c", "x", &[]));
+        w.rooms.insert("d".into(), room("d", "y", &[(Direction::East, "c", ExitState::AlwaysOpen)]));
+
+        let mut opts = PathOptions::default();
+        assert_eq!(vec![Direction::South, Direction::East], find_path(&w, "a", "c", &opts).await.unwrap());
+        opts.areas = Some(HashSet::from(["x".to_string()]));
+        assert_eq!(Err(PathError::NoRoute), find_path(&w, "a", "c", &opts).await);
+        opts.through_closed = true;
+        assert_eq!(vec![Direction::East, Direction::North], find_path(&w, "a", "c", &opts).await.unwrap());
+        assert!(find_path(&w, "a", "a", &opts).await.unwrap().is_empty());
+        assert_eq!(Err(PathError::NoSuchRoom("z".into())), find_path(&w, "a", "z", &opts).await);
+    }
+
+    #[tokio::test]
+    async fn hidden_exits_once_known() {
+        let mut w = World::blank();
+        let secret = room("a", "x", &[(Direction::Down, "b", ExitState::AlwaysOpen)]);
+        secret.write().await.exits.get_mut(&Direction::Down).unwrap().hidden = Some(serde_json::from_str("{}").unwrap());
+        w.rooms.insert("a".into(), secret);
+        w.rooms.insert("b".into(), room("b", "x", &[]));
+
+        let mut opts = PathOptions::default();
+        assert_eq!(Err(PathError::NoRoute), find_path(&w, "a", "b", &opts).await);
+        opts.known_exits.insert(secret_id("a", &Direction::Down));
+        assert_eq!(vec![Direction::Down], find_path(&w, "a", "b", &opts).await.unwrap());
+    }
+}
@@ -6,7 +6,12 @@ use once_cell::sync::Lazy;
 use serde::{Deserialize, Deserializer, Serialize};
 use tokio::sync::RwLock;
 
-use crate::{DATA_PATH, item::{Item, ItemError, inventory::{Container, ContainerType, Storage, StorageCapacity}}, player::Player, traits::{Description, IdentityQuery, save::{DoesSave, SaveError}}, util::{Editor, direction::Direction}, world::{SharedWorld, area::Area, exit::{Exit, state::ExitState}}};
+pub mod flags;
+pub mod delete;
+pub mod template;
+use flags::{RoomFlag, Sector};
+
+use crate::{DATA_PATH, item::{Item, ItemError, inventory::{Container, ContainerType, Storage, StorageCapacity}}, mob::Mob, player::Player, traits::{Description, IdentityQuery, save::{DoesSave, SaveError}}, util::{Editor, direction::Direction}, world::{SharedWorld, area::Area, draft::{Draft, RoomRevision}, exit::{Exit, state::ExitState}}};
 
 pub(crate) static ROOM_PATH: Lazy<Arc<String>> = Lazy::new(|| Arc::new(format!("{}/rooms", *DATA_PATH)));
 /// Max number of items in a [Room], whether on ground or otherwise.
@@ -26,6 +31,12 @@ pub(crate) struct Room {
     pub title: String,
     pub description: String,
     pub exits: HashMap<Direction, Exit>,
+
+    /// Assorted [RoomFlag]s, if any.
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub flags: HashSet<RoomFlag>,
+    #[serde(default)]
+    pub sector: Sector,
     
     /// Parent [Area] ID.
     #[serde(default = "room_parent_id_default")]
@@ -38,9 +49,23 @@ pub(crate) struct Room {
     /// Weak lock to [Player] entities currently present in the [Room].
     #[serde(skip, default)]
     pub players: HashMap<String, Weak<RwLock<Player>>>,
+
+    /// [Mob]s currently present in the [Room], by their ID.
+    ///
+    /// Mobs are not persisted along with the room — [resets][crate::world::area::reset::Reset]
+    /// (re)populate rooms as needed.
+    #[serde(skip, default)]
+    pub mobs: HashMap<String, Mob>,
     
     /// [Room] [contents][Container]… a.k.a. whatever lies around.
     pub contents: Container,
+
+    /// Set while the room is a draft, hidden from players.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<Draft>,
+    /// Edits waiting to be published, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<RoomRevision>,
 }
 
 impl <'de> Deserialize<'de> for Room {
@@ -52,9 +77,17 @@ impl <'de> Deserialize<'de> for Room {
             title: String,
             description: String,
             exits: HashMap<Direction, Exit>,
+            #[serde(default)]
+            flags: HashSet<RoomFlag>,
+            #[serde(default)]
+            sector: Sector,
             #[serde(default = "room_parent_id_default")]
             parent_id: String,
             contents: Option<Container>,
+            #[serde(default)]
+            draft: Option<Draft>,
+            #[serde(default)]
+            revision: Option<RoomRevision>,
         }
 
         let rf: RoomFile = Deserialize::deserialize(deserializer)?;
@@ -62,10 +95,15 @@ impl <'de> Deserialize<'de> for Room {
             title: rf.title,
             description: rf.description,
             exits: rf.exits,
+            flags: rf.flags,
+            sector: rf.sector,
             parent_id: rf.parent_id,
             parent: Weak::new(),
             contents: rf.contents.unwrap_or_else(|| Room::default_container(&rf.id)),
             players: HashMap::new(),
+            mobs: HashMap::new(),
+            draft: rf.draft,
+            revision: rf.revision,
             id: rf.id,
         })
     }
@@ -120,10 +158,15 @@ impl Room {
             title: "".into(),
             description: "".into(),
             exits: HashMap::new(),
+            flags: HashSet::new(),
+            sector: Sector::default(),
             parent_id: "root".into(),
             parent: Weak::new(),
             players: HashMap::new(),
+            mobs: HashMap::new(),
             contents: Room::default_container(&id),
+            draft: None,
+            revision: None,
             id,
         }
     }
@@ -162,11 +205,32 @@ impl Room {
         }
     }
 
+    /// Put a [Mob] into the [Room].
+    pub fn add_mob(&mut self, mob: Mob) {
+        log::debug!("Mob '{}' added to room '{}'", mob.id(), self.id());
+        self.mobs.insert(mob.id().into(), mob);
+    }
+
+    /// Find a [Mob] in the [Room] by (partial) `name`.
+    pub fn find_mob(&self, name: &str) -> Option<&Mob> {
+        self.mobs.get(name).or_else(|| self.mobs.values().find(|m| m.answers_to(name)))
+    }
+
     /// Generate a Room-[Container] for `id`.
     fn default_container(id: &str) -> Container {
         Container::from(ContainerType::Room(id.into()))
     }
 
+    /// See if the [Room] has the given [RoomFlag] set.
+    pub fn has_flag(&self, flag: RoomFlag) -> bool {
+        self.flags.contains(&flag)
+    }
+
+    /// Under the open sky?
+    pub fn is_outdoors(&self) -> bool {
+        !self.has_flag(RoomFlag::Indoors) && self.sector != Sector::Underwater
+    }
+
     /// Change state of one of the [Room] [Exit].
     pub fn set_exit_state(&mut self, exit: Direction, state: ExitState) {
         if let Some(exit) = self.exits.get_mut(&exit) {
@@ -293,6 +357,8 @@ impl Room {
         self.description = other.description.clone();
         self.title = other.title.clone();
         self.exits = other.exits.clone();
+        self.flags = other.flags.clone();
+        self.sector = other.sector;
     }
 }
 
@@ -311,5 +377,25 @@ mod room_tests {
             "parent_id": "root"
         }"#).unwrap();
         assert_eq!(room.id, "nexus");
-    }    
+    }
+
+    #[test]
+    fn flags_and_sector() {
+        use crate::world::room::flags::{RoomFlag, Sector};
+        let room: Room = serde_json::from_str(r#"{
+            "id": "pond",
+            "title": "A Pond",
+            "description": "Wet.",
+            "exits": {},
+            "flags": ["Dark", "Safe"],
+            "sector": "Water"
+        }"#).unwrap();
+        assert!(room.has_flag(RoomFlag::Dark) && room.has_flag(RoomFlag::Safe));
+        assert!(!room.has_flag(RoomFlag::NoTeleport));
+        assert_eq!(Sector::Water, room.sector);
+        let json = serde_json::to_string(&room).unwrap();
+        let again: Room = serde_json::from_str(&json).unwrap();
+        assert_eq!(room.flags, again.flags);
+        assert_eq!(Ok(RoomFlag::NoTeleport), RoomFlag::try_from("no-teleport"));
+    }
 }
@@ -0,0 +1,252 @@
+//! Room deletion — planned first, carried out only once confirmed.
+//!
+//! Exits and area resets leading into a doomed [Room][super::Room] get stripped,
+//! occupants and whatever lies around get moved to a refuge room, and the `.room`
+//! file gets archived rather than erased.
+use std::{fmt::Display, fs, path::PathBuf};
+
+use crate::{cmd::translocate::translocate, item::inventory::Storage, traits::{IdentityQuery, save::{DoesSave, SaveError}}, util::{direction::Direction, timestamp::unix_now}, world::{SharedWorld, World, room::ROOM_PATH}};
+
+/// Where deleted room files end up.
+fn archive_path() -> String { format!("{}/archive", *ROOM_PATH) }
+
+#[derive(Debug)]
+pub enum DeletionError {
+    NotFound(String),
+    RootRoom,
+    NoRefuge,
+    Save(SaveError),
+}
+
+impl std::error::Error for DeletionError {}
+impl From<SaveError> for DeletionError { fn from(value: SaveError) -> Self { Self::Save(value) }}
+impl From<std::io::Error> for DeletionError { fn from(value: std::io::Error) -> Self { Self::Save(value.into()) }}
+
+impl Display for DeletionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(id) => write!(f, "No such room as '{}'", id),
+            Self::RootRoom => write!(f, "The world's root room cannot be deleted"),
+            Self::NoRefuge => write!(f, "No room to move the occupants into"),
+            Self::Save(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// An exit, in some other room, that leads into the doomed room.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InboundExit {
+    pub room_id: String,
+    pub area_id: String,
+    pub dir: Direction,
+}
+
+/// Everything a room deletion would affect.
+#[derive(Debug, Clone)]
+pub struct DeletionPlan {
+    pub room_id: String,
+    pub area_id: String,
+    /// Where occupants and items go.
+    pub refuge: String,
+    pub inbound: Vec<InboundExit>,
+    /// Areas with resets targeting the room, and how many such resets each, sorted.
+    pub resets: Vec<(String, usize)>,
+    pub players: Vec<String>,
+    pub mobs: Vec<String>,
+    pub items: Vec<String>,
+}
+
+impl DeletionPlan {
+    /// IDs of all the areas the deletion touches, sorted.
+    pub fn areas(&self) -> Vec<String> {
+        let mut areas = self.inbound.iter().map(|e| e.area_id.clone()).collect::<Vec<_>>();
+        areas.extend(self.resets.iter().map(|(id, _)| id.clone()));
+        areas.push(self.area_id.clone());
+        areas.sort();
+        areas.dedup();
+        areas
+    }
+}
+
+/// Figure out what deleting `room_id` would involve.
+pub async fn plan(world: &World, room_id: &str) -> Result<DeletionPlan, DeletionError> {
+    if room_id == world.root.room {
+        return Err(DeletionError::RootRoom);
+    }
+    let Some(room) = world.rooms.get(room_id) else {
+        return Err(DeletionError::NotFound(room_id.into()));
+    };
+    let r = room.read().await;
+
+    // Prefer a neighbour; fall back to the root room.
+    let mut ways = r.exits.values()
+        .map(|e| e.destination.clone())
+        .filter(|d| d != room_id && world.rooms.contains_key(d))
+        .collect::<Vec<_>>();
+    ways.sort();
+    let refuge = match ways.into_iter().next() {
+        Some(id) => id,
+        None if world.rooms.contains_key(&world.root.room) => world.root.room.clone(),
+        None => return Err(DeletionError::NoRefuge)
+    };
+
+    let mut inbound = vec![];
+    for (id, other) in &world.rooms {
+        if id == room_id {
+            continue;
+        }
+        let o = other.read().await;
+        for (dir, exit) in &o.exits {
+            if exit.destination == room_id {
+                inbound.push(InboundExit { room_id: id.clone(), area_id: o.parent_id.clone(), dir: dir.clone() });
+            }
+        }
+    }
+    inbound.sort_by(|a, b| (&a.room_id, a.dir.to_string()).cmp(&(&b.room_id, b.dir.to_string())));
+
+    let mut resets = vec![];
+    for (id, area) in &world.areas {
+        let num = area.read().await.resets.iter().filter(|r| r.room_id() == room_id).count();
+        if num > 0 {
+            resets.push((id.clone(), num));
+        }
+    }
+    resets.sort();
+
+    let mut players = r.players.keys().cloned().collect::<Vec<_>>();
+    players.sort();
+    let mut mobs = r.mobs.values().map(|m| m.title().to_string()).collect::<Vec<_>>();
+    mobs.sort();
+    let mut items = r.contents.items().values().map(|i| i.title().to_string()).collect::<Vec<_>>();
+    items.sort();
+
+    Ok(DeletionPlan {
+        room_id: room_id.into(),
+        area_id: r.parent_id.clone(),
+        refuge,
+        inbound,
+        resets,
+        players,
+        mobs,
+        items,
+    })
+}
+
+/// Carry out the deletion `plan`.
+///
+/// Re-plan right before calling this; the world doesn't stand still.
+pub async fn execute(world: &SharedWorld, plan: &DeletionPlan) -> Result<(), DeletionError> {
+    let Some(room) = world.read().await.rooms.get(&plan.room_id).cloned() else {
+        return Err(DeletionError::NotFound(plan.room_id.clone()));
+    };
+
+    // Players first — translocation deals with the world lock by itself.
+    let players = room.read().await.players.values().filter_map(|p| p.upgrade()).collect::<Vec<_>>();
+    for p in players {
+        if let Err(e) = translocate(world, Some(plan.room_id.clone()), plan.refuge.clone(), p.clone()).await {
+            log::error!("Could not move '{}' out of '{}': {:?}", p.read().await.id(), plan.room_id, e);
+        }
+    }
+
+    let mut w = world.write().await;
+    let Some(refuge) = w.rooms.get(&plan.refuge).cloned() else {
+        return Err(DeletionError::NoRefuge);
+    };
+    {
+        let mut r = room.write().await;
+        let mut dst = refuge.write().await;
+        for (_, mob) in r.mobs.drain() {
+            dst.add_mob(mob);
+        }
+        let ids = r.contents.items().keys().cloned().collect::<Vec<_>>();
+        for id in ids {
+            let Ok(item) = r.take_out(&id) else { continue };
+            if let Err(e) = dst.try_insert(item) {
+                w.lost_and_found.insert(e.id().to_string(), e);
+            }
+        }
+        dst.save().await?;
+    }
+
+    for exit in &plan.inbound {
+        let Some(other) = w.rooms.get(&exit.room_id) else { continue };
+        let mut o = other.write().await;
+        if o.exits.get(&exit.dir).is_some_and(|e| e.destination == plan.room_id) {
+            o.exits.remove(&exit.dir);
+            o.save().await?;
+        }
+    }
+
+    for (area_id, _) in &plan.resets {
+        let Some(area) = w.areas.get(area_id) else { continue };
+        let mut a = area.write().await;
+        a.resets.retain(|r| r.room_id() != plan.room_id);
+        a.save().await?;
+    }
+
+    w.rooms.remove(&plan.room_id);
+    if let Some(area) = w.areas.get(&plan.area_id) {
+        area.write().await.rooms.remove(&plan.room_id);
+    }
+    // the touched rooms and areas got saved above already.
+    w.save_world_file()?;
+    drop(w);
+
+    // Archive rather than erase; the room may be missed yet.
+    let path = PathBuf::from(format!("{}/{}.room", *ROOM_PATH, plan.room_id));
+    if path.exists() {
+        fs::create_dir_all(archive_path())?;
+        fs::rename(&path, format!("{}/{}.{}.room", archive_path(), plan.room_id, unix_now()))?;
+    }
+    Ok(())
+}
+
+/// Tell the affected bits of `plan` in a user-readable form.
+pub fn fmt_plan(plan: &DeletionPlan) -> String {
+    let list = |v: &[String]| if v.is_empty() {"-".to_string()} else {v.join(", ")};
+    let mut out = format!("<c yellow>-[ DELETE: {} ]-</c>\n", plan.room_id);
+    out.push_str(&format!("  Area: <c cyan>{}</c>\n", plan.area_id));
+    out.push_str(&format!("  Exits to strip: {}\n", if plan.inbound.is_empty() {"-".into()} else {
+        plan.inbound.iter().map(|e| format!("<c cyan>{}</c>:{}", e.room_id, e.dir)).collect::<Vec<_>>().join(", ")
+    }));
+    out.push_str(&format!("  Resets to drop: {}\n", if plan.resets.is_empty() {"-".into()} else {
+        plan.resets.iter().map(|(a, n)| format!("<c cyan>{}</c>:{}", a, n)).collect::<Vec<_>>().join(", ")
+    }));
+    out.push_str(&format!("  Players: {}\n", list(&plan.players)));
+    out.push_str(&format!("  Mobs: {}\n", list(&plan.mobs)));
+    out.push_str(&format!("  Items: {}\n", list(&plan.items)));
+    out.push_str(&format!("  Occupants and items go to: <c cyan>{}</c>\n", plan.refuge));
+    out
+}
+
+#[cfg(test)]
+mod delete_tests {
+    use std::sync::Arc;
+
+    use tokio::sync::RwLock;
+
+    use crate::{world::{area::{Area, reset::Reset}, exit::{Exit, state::ExitState}, room::Room}, world_for_tests};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn plan_deletion() {
+        let w = world_for_tests!();
+        let w = w.read().await;
+        w.areas["root"].write().await.resets.push(Reset::Door { room: "void".into(), exit: Direction::East, state: ExitState::AlwaysOpen, message: None });
+        let p = plan(&w, "void").await.unwrap();
+        assert_eq!("clearing", p.refuge);
+        assert_eq!(vec![("root".to_string(), 1)], p.resets);
+        assert_eq!(vec![InboundExit { room_id: "clearing".into(), area_id: "area".into(), dir: Direction::West }], p.inbound);
+        assert_eq!(vec!["area".to_string(), "root".into()], p.areas());
+        assert!(matches!(plan(&w, "nowhere").await, Err(DeletionError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn root_room_stays() {
+        let w = world_for_tests!();
+        let mut w = w.write().await;
+        w.root.room = "void".into();
+        assert!(matches!(plan(&w, "void").await, Err(DeletionError::RootRoom)));
+    }
+}
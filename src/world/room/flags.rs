@@ -0,0 +1,100 @@
+//! Room flags and sector types.
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Various per-[Room][crate::world::room::Room] properties.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RoomFlag {
+    /// Can't see a thing without a light source.
+    Dark,
+    /// No hostilities allowed.
+    Safe,
+    /// No translocating in (or out), except for admins.
+    NoTeleport,
+    /// Roofed over — no sky, no weather.
+    Indoors,
+}
+
+/// The general lay of the land.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum Sector {
+    #[default]
+    City,
+    Field,
+    Forest,
+    Hills,
+    Mountain,
+    Desert,
+    Water,
+    Underwater,
+    Air,
+}
+
+impl RoomFlag {
+    pub const ALL: [Self; 4] = [Self::Dark, Self::Safe, Self::NoTeleport, Self::Indoors];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Dark => "dark",
+            Self::Safe => "safe",
+            Self::NoTeleport => "noteleport",
+            Self::Indoors => "indoors",
+        }
+    }
+}
+
+impl Sector {
+    pub const ALL: [Self; 9] = [
+        Self::City, Self::Field, Self::Forest, Self::Hills, Self::Mountain,
+        Self::Desert, Self::Water, Self::Underwater, Self::Air
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::City => "city",
+            Self::Field => "field",
+            Self::Forest => "forest",
+            Self::Hills => "hills",
+            Self::Mountain => "mountain",
+            Self::Desert => "desert",
+            Self::Water => "water",
+            Self::Underwater => "underwater",
+            Self::Air => "air",
+        }
+    }
+}
+
+impl Display for RoomFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Display for Sector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for RoomFlag {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.to_lowercase();
+        Self::ALL.into_iter()
+            .find(|f| f.as_str() == value || (value == "no-teleport" && *f == Self::NoTeleport))
+            .ok_or("No such room flag…")
+    }
+}
+
+impl TryFrom<&str> for Sector {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.to_lowercase();
+        Self::ALL.into_iter()
+            .find(|s| s.as_str() == value)
+            .ok_or("No such sector type…")
+    }
+}
@@ -0,0 +1,209 @@
+//! Room templates, one `<id>.json` file each under `data/room_templates`.
+//!
+//! A template carries the looks of a [Room] — title, description, flags and
+//! sector — plus an exit layout pattern: which ways the room opens to, and
+//! how (doors, locks). Destinations get filled in when rooms are laid out.
+use std::{collections::{HashMap, HashSet}, sync::Arc};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::{DATA_PATH, traits::{IdentityQuery, save::{DoesSave, SaveError}}, util::direction::Direction, world::{World, exit::{Exit, state::ExitState}, room::{Room, flags::{RoomFlag, Sector}}}};
+
+pub(crate) static ROOM_TEMPLATE_PATH: Lazy<String> = Lazy::new(|| format!("{}/room_templates", *DATA_PATH));
+pub(crate) static ROOM_TEMPLATES: Lazy<Arc<RwLock<RoomTemplateRegistry>>> = Lazy::new(|| Arc::new(RwLock::new(RoomTemplateRegistry::default())));
+/// Largest grid side `dig grid` is willing to make.
+pub(crate) const MAX_GRID_SIDE: usize = 20;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RoomTemplate {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub flags: HashSet<RoomFlag>,
+    #[serde(default)]
+    pub sector: Sector,
+    /// Exit layout; empty = any way goes.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub exits: HashMap<Direction, ExitState>,
+}
+
+impl IdentityQuery for RoomTemplate {
+    fn id(&self) -> &str { &self.id }
+    fn title(&self) -> &str { &self.title }
+}
+
+impl RoomTemplate {
+    /// Capture `room` as template `id`.
+    pub fn from_room(id: &str, room: &Room) -> Self {
+        Self {
+            id: id.into(),
+            title: room.title.clone(),
+            description: room.description.clone(),
+            flags: room.flags.clone(),
+            sector: room.sector,
+            exits: room.exits.iter().map(|(d, e)| (d.clone(), e.state.clone())).collect(),
+        }
+    }
+
+    /// A fresh, exitless [Room] `id` off of the template.
+    pub fn instantiate(&self, id: &str) -> Room {
+        let mut room = Room::blank(Some(id));
+        room.title = self.title.clone();
+        room.description = self.description.clone();
+        room.flags = self.flags.clone();
+        room.sector = self.sector;
+        room
+    }
+
+    /// Does the layout allow an exit towards `dir`?
+    pub fn allows(&self, dir: &Direction) -> bool {
+        self.exits.is_empty() || self.exits.contains_key(dir)
+    }
+
+    /// Exit towards `destination` in `dir`, as the layout has it.
+    pub fn exit(&self, dir: &Direction, destination: &str) -> Exit {
+        Exit {
+            destination: destination.into(),
+            state: self.exits.get(dir).cloned().unwrap_or(ExitState::Open { key_id: None }),
+            hidden: None,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DoesSave for RoomTemplate {
+    async fn save(&mut self) -> Result<(), SaveError> {
+        tokio::fs::create_dir_all(ROOM_TEMPLATE_PATH.as_str()).await?;
+        tokio::fs::write(format!("{}/{}.json", *ROOM_TEMPLATE_PATH, self.id), serde_json::to_string_pretty(self)?).await?;
+        Ok(())
+    }
+}
+
+/// All the known [RoomTemplate]s.
+#[derive(Debug, Default)]
+pub struct RoomTemplateRegistry {
+    templates: HashMap<String, RoomTemplate>,
+}
+
+impl RoomTemplateRegistry {
+    /// Load all templates from [ROOM_TEMPLATE_PATH].
+    ///
+    /// Malformed files are skipped (with a warning).
+    pub async fn load() -> Result<Self, std::io::Error> {
+        tokio::fs::create_dir_all(ROOM_TEMPLATE_PATH.as_str()).await?;
+        let mut templates = HashMap::new();
+        let mut dir = tokio::fs::read_dir(ROOM_TEMPLATE_PATH.as_str()).await?;
+        while let Some(entry) = dir.next_entry().await? {
+            let path = entry.path();
+            if !path.is_file() || path.extension().and_then(|s| s.to_str()) != Some("json") {
+                continue;
+            }
+            match serde_json::from_str::<RoomTemplate>(&tokio::fs::read_to_string(&path).await?) {
+                Ok(t) => { templates.insert(t.id.clone(), t); },
+                Err(e) => log::warn!("Room template '{}' malformed: {e:?}", path.display()),
+            }
+        }
+        log::info!("{} room template(s) loaded.", templates.len());
+        Ok(Self { templates })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&RoomTemplate> {
+        self.templates.get(id)
+    }
+
+    pub fn insert(&mut self, template: RoomTemplate) {
+        self.templates.insert(template.id.clone(), template);
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<RoomTemplate> {
+        self.templates.remove(id)
+    }
+
+    /// All templates, sorted by ID.
+    pub fn list(&self) -> Vec<&RoomTemplate> {
+        let mut list = self.templates.values().collect::<Vec<_>>();
+        list.sort_by(|a, b| a.id.cmp(&b.id));
+        list
+    }
+}
+
+/// Find template `source`: a room (by ID) to take after, or a named template.
+pub async fn resolve(world: &World, source: &str) -> Option<RoomTemplate> {
+    if let Some(room) = world.rooms.get(source) {
+        return Some(RoomTemplate::from_room(source, &*room.read().await));
+    }
+    ROOM_TEMPLATES.read().await.get(source).cloned()
+}
+
+/// ID of grid cell at `row`,`col`.
+pub fn grid_id(prefix: &str, row: usize, col: usize) -> String {
+    format!("{}-{}-{}", prefix, row + 1, col + 1)
+}
+
+/// Lay out a `cols`×`rows` block of rooms off of `template`, linked with
+/// their neighbours as the layout allows — east/west along rows, north/south
+/// along columns.
+pub fn grid(template: &RoomTemplate, prefix: &str, cols: usize, rows: usize) -> Vec<Room> {
+    let mut rooms = vec![];
+    for row in 0..rows {
+        for col in 0..cols {
+            let mut room = template.instantiate(&grid_id(prefix, row, col));
+            let neighbours = [
+                (Direction::North, row > 0, row.wrapping_sub(1), col),
+                (Direction::South, row + 1 < rows, row + 1, col),
+                (Direction::West, col > 0, row, col.wrapping_sub(1)),
+                (Direction::East, col + 1 < cols, row, col + 1),
+            ];
+            for (dir, exists, r, c) in neighbours {
+                // the layout goes by axis - either way along it will do.
+                let back = dir.opposite().unwrap_or(dir.clone());
+                if exists && (template.allows(&dir) || template.allows(&back)) {
+                    let exit = template.exit(&dir, &grid_id(prefix, r, c));
+                    room.exits.insert(dir, exit);
+                }
+            }
+            rooms.push(room);
+        }
+    }
+    rooms
+}
+
+#[cfg(test)]
+mod template_tests {
+    use super::*;
+
+    fn template(exits: &[Direction]) -> RoomTemplate {
+        RoomTemplate {
+            id: "road".into(),
+            title: "A Road".into(),
+            description: "Dusty.".into(),
+            flags: HashSet::new(),
+            sector: Sector::Field,
+            exits: exits.iter().map(|d| (d.clone(), ExitState::AlwaysOpen)).collect(),
+        }
+    }
+
+    #[test]
+    fn full_grid() {
+        let rooms = grid(&template(&[]), "f", 3, 2);
+        assert_eq!(6, rooms.len());
+        let corner = rooms.iter().find(|r| r.id == "f-1-1").unwrap();
+        assert_eq!(2, corner.exits.len());
+        assert_eq!("f-1-2", corner.exits[&Direction::East].destination);
+        assert_eq!("f-2-1", corner.exits[&Direction::South].destination);
+        let middle = rooms.iter().find(|r| r.id == "f-1-2").unwrap();
+        assert_eq!(3, middle.exits.len());
+        assert!(rooms.iter().all(|r| r.title == "A Road" && r.sector == Sector::Field));
+    }
+
+    #[test]
+    fn layout_limits_grid() {
+        // an east-west road doesn't link rows together.
+        let rooms = grid(&template(&[Direction::East]), "r", 2, 2);
+        assert!(rooms.iter().all(|r| r.exits.len() == 1));
+        assert!(rooms.iter().all(|r| !r.exits.contains_key(&Direction::South)));
+    }
+}
@@ -0,0 +1,85 @@
+//! Weather — per [Area][crate::world::area::Area], shifting by the hour.
+use std::fmt::Display;
+
+use dicebag::DiceExt;
+
+use crate::world::calendar::Season;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Weather {
+    #[default]
+    Clear,
+    Rain,
+    Storm,
+    Snow,
+}
+
+/// Chance (%) per game hour for the weather to shift at all.
+const CHANGE_CHANCE: i32 = 20;
+
+impl Weather {
+    /// What comes next, given the `season`?
+    pub fn next(&self, season: Season) -> Self {
+        if 1.d100() > CHANGE_CHANCE {
+            return *self;
+        }
+        let precipitation = if season == Season::Winter { Self::Snow } else { Self::Rain };
+        match (self, 1.d6()) {
+            (Self::Clear, _) => precipitation,
+            (Self::Rain | Self::Snow, 1..=4) => Self::Clear,
+            (Self::Rain | Self::Snow, _) if season != Season::Winter => Self::Storm,
+            (Self::Storm, _) => precipitation,
+            _ => *self
+        }
+    }
+
+    /// What the change from `self` to `next` looks like from outdoors.
+    pub fn change_message(&self, next: Weather) -> Option<&'static str> {
+        Some(match (self, next) {
+            (a, b) if *a == b => return None,
+            (_, Self::Clear) => "The clouds part and the sky clears.",
+            (Self::Storm, Self::Rain) => "The storm calms down into a steady rain.",
+            (_, Self::Rain) => "It starts to rain.",
+            (_, Self::Storm) => "Thunder rumbles as a storm breaks loose!",
+            (_, Self::Snow) => "Snowflakes begin to drift down from the sky.",
+        })
+    }
+
+    /// The look of the sky, day or night.
+    pub fn describe(&self, daytime: bool) -> &'static str {
+        match (self, daytime) {
+            (Self::Clear, true) => "The sun shines from a clear sky.",
+            (Self::Clear, false) => "Stars glitter in the clear night sky.",
+            (Self::Rain, _) => "Rain pours down from a gray sky.",
+            (Self::Storm, _) => "A storm rages, lightning flashing across the sky.",
+            (Self::Snow, _) => "Snow falls softly all around.",
+        }
+    }
+}
+
+impl Display for Weather {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Clear => "clear",
+            Self::Rain => "rain",
+            Self::Storm => "storm",
+            Self::Snow => "snow",
+        })
+    }
+}
+
+#[cfg(test)]
+mod weather_tests {
+    use super::*;
+
+    #[test]
+    fn seasonal_shifts() {
+        for _ in 0..500 {
+            // no rain in winter, no snow otherwise…
+            assert_ne!(Weather::Rain, Weather::Clear.next(Season::Winter));
+            assert_ne!(Weather::Snow, Weather::Clear.next(Season::Summer));
+        }
+        assert!(Weather::Rain.change_message(Weather::Rain).is_none());
+        assert!(Weather::Clear.change_message(Weather::Storm).is_some());
+    }
+}
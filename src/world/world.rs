@@ -9,13 +9,13 @@
 //! cause e.g. saved locations in player saves to be invalid.
 //! 
 //! If one or the other file is missing… Bad Things™ will happen!
-use std::{collections::HashMap, fmt::Display, fs::read_to_string, net::SocketAddr, path::PathBuf, str::FromStr, sync::Arc};
+use std::{collections::HashMap, fmt::Display, fs::read_to_string, net::SocketAddr, os::fd::RawFd, path::PathBuf, str::FromStr, sync::Arc};
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
-use crate::{DATA_PATH, item::ItemError, player::Player, string::{Sluggable, prompt::PromptType}, traits::{save::{DoesSave, SaveError}, tickable::Tickable}, util::contact::{AdminInfo, Contact}, world::{area::Area, room::Room}};
+use crate::{DATA_PATH, item::ItemError, player::Player, string::{Sluggable, prompt::PromptType}, traits::{IdentityQuery, save::{DoesSave, SaveError}, tickable::Tickable}, util::contact::{AdminInfo, Contact}, world::{area::{Area, AreaMessage}, calendar::{GameTime, SUNRISE_HOUR, SUNSET_HOUR, TICKS_PER_HOUR}, room::Room, weather::Weather}};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MotD {
@@ -46,24 +46,19 @@ mod area_serialization {
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
     use tokio::sync::RwLock;
 
-    use crate::{traits::{IdentityQuery, save::DoesSave}, world::area::AREA_PATH};
+    use crate::world::area::AREA_PATH;
 
     use super::Area;
 
+    /// Serialize the list of [Area] IDs.
+    ///
+    /// The [Area]s themselves are saved by [World::save][super::World].
     pub fn serialize<S: Serializer>(areas: &HashMap<String, Arc<RwLock<Area>>>, serializer: S) -> Result<S::Ok, S::Error>
     where S: Serializer,
     {
-        let runtime = tokio::runtime::Runtime::new().unwrap();
-        for (_, area) in areas {
-            runtime.block_on(async {
-                // TODO: _ …
-                let _ = area.write().await.save().await;
-            });
-        }
-        
-        areas.keys()
-            .collect::<Vec<&String>>()
-            .serialize(serializer)
+        let mut stems = areas.keys().collect::<Vec<&String>>();
+        stems.sort();
+        stems.serialize(serializer)
     }
 
     pub fn deserialize<'de, D>(deserializer: D) -> Result<HashMap<String, Arc<RwLock<Area>>>, D::Error>
@@ -94,24 +89,19 @@ mod room_serialization {
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
     use tokio::sync::RwLock;
 
-    use crate::{traits::save::DoesSave, world::room::ROOM_PATH};
+    use crate::world::room::ROOM_PATH;
 
     use super::Room;
 
+    /// Serialize the list of [Room] IDs.
+    ///
+    /// The [Room]s themselves are saved by [World::save][super::World].
     pub fn serialize<S: Serializer>(rooms: &HashMap<String, Arc<RwLock<Room>>>, serializer: S) -> Result<S::Ok, S::Error>
     where S: Serializer,
     {
-        let runtime = tokio::runtime::Runtime::new().unwrap();
-        for (_, room) in rooms {
-            runtime.block_on(async {
-                // TODO: _ …
-                let _ = room.write().await.save().await;
-            });
-        }
-
-        rooms.keys()
-            .collect::<Vec<&String>>()
-            .serialize(serializer)
+        let mut stems = rooms.keys().collect::<Vec<&String>>();
+        stems.sort();
+        stems.serialize(serializer)
     }
 
     pub fn deserialize<'de, D>(deserializer: D) -> Result<HashMap<String, Arc<RwLock<Room>>>, D::Error>
@@ -155,6 +145,10 @@ pub struct World {
     #[serde(skip, default)] pub players_by_sockaddr: HashMap<SocketAddr, Arc<RwLock<Player>>>,
     #[serde(skip, default)] pub players: HashMap<String, Arc<RwLock<Player>>>,
     #[serde(skip, default)] pub players_to_logout: Vec<Arc<RwLock<Player>>>,
+    /// Raw sockets of connected clients, for copyover.
+    #[serde(skip, default)] pub session_fds: HashMap<SocketAddr, RawFd>,
+    /// Raw listening socket, for copyover.
+    #[serde(skip, default)] pub listener_fd: Option<RawFd>,
 
     #[serde(with = "area_serialization")] pub areas: HashMap<String, Arc<RwLock<Area>>>,
     #[serde(with = "room_serialization")] pub rooms: HashMap<String, Arc<RwLock<Room>>>,
@@ -229,6 +223,8 @@ impl World {
         players_by_sockaddr: HashMap::new(),
         players: HashMap::new(),
         players_to_logout: vec![],
+        session_fds: HashMap::new(),
+        listener_fd: None,
         rooms: HashMap::new(),
         lost_and_found: HashMap::new(),
     }}
@@ -306,6 +302,129 @@ impl World {
         r.or(Some(Ok(self))).unwrap()
     }
 
+    /// Collect (and empty) the [outboxes][Area::outbox] of all areas.
+    ///
+    /// # Returns
+    /// `(area ID, message)` pairs.
+    pub async fn take_area_messages(&self) -> Vec<(String, AreaMessage)> {
+        let mut messages = vec![];
+        for (id, area) in &self.areas {
+            let mut a = area.write().await;
+            messages.extend(a.outbox.drain(..).map(|m| (id.clone(), m)));
+        }
+        messages
+    }
+
+    /// Mirror the doors put back by area resets onto their other side, as long as
+    /// that side leads back — so that both sides agree, like after 'open', 'lock', etc.
+    pub async fn mirror_reset_doors(&self) {
+        for area in self.areas.values() {
+            let doors = std::mem::take(&mut area.write().await.reset_doors);
+            for (room_id, dir) in doors {
+                let Some(exit) = (match self.rooms.get(&room_id) {
+                    Some(r) => r.read().await.exits.get(&dir).cloned(),
+                    None => None
+                }) else { continue };
+                let Ok(back) = dir.opposite() else {
+                    log::debug!("Reset door {} of '{}': no known opposite, other side left as-is.", dir, room_id);
+                    continue;
+                };
+                let Some(other) = self.rooms.get(&exit.destination) else { continue };
+                match other.write().await.exits.get_mut(&back) {
+                    Some(e) if e.destination == room_id => e.state = exit.state.clone(),
+                    _ => log::debug!("Reset door {} of '{}': no way back from '{}'.", dir, room_id, exit.destination)
+                }
+            }
+        }
+    }
+
+    /// World age, in ticks.
+    pub fn uptime(&self) -> u64 {
+        self.uptime
+    }
+
+    /// Current game time.
+    pub fn time(&self) -> GameTime {
+        GameTime::from_ticks(self.uptime)
+    }
+
+    /// Save just the base #.world file, sans areas and rooms.
+    ///
+    /// For when only a handful of areas/rooms changed — save those separately.
+    pub fn save_world_file(&self) -> Result<(), SaveError> {
+        let file = std::fs::File::create(&self.filename)?;
+        serde_json::to_writer_pretty(file, &self)?;
+        Ok(())
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Weather at `room_id`, i.e. within its area.
+    pub async fn weather_at(&self, room_id: &str) -> Weather {
+        let area_id = match self.rooms.get(room_id) {
+            Some(room) => room.read().await.parent_id.clone(),
+            None => return Weather::default()
+        };
+        match self.areas.get(&area_id) {
+            Some(area) => area.read().await.weather,
+            None => Weather::default()
+        }
+    }
+
+    /// May `player` build within `area_id`?
+    ///
+    /// Admins may build anywhere, other builders only where listed.
+    pub async fn may_build(&self, player: &Player, area_id: &str) -> bool {
+        if player.access.is_admin() {
+            return true;
+        }
+        if !player.access.is_builder() {
+            return false;
+        }
+        match self.areas.get(area_id) {
+            Some(area) => area.read().await.may_build(player.id()),
+            None => false
+        }
+    }
+
+    /// Is `room_id` (or its area) a draft, i.e. off limits for players?
+    pub async fn is_draft(&self, room_id: &str) -> bool {
+        match self.rooms.get(room_id) {
+            Some(room) => self.is_draft_room(&*room.read().await).await,
+            None => false
+        }
+    }
+
+    /// Is the (already locked) `room` or its area a draft?
+    pub async fn is_draft_room(&self, room: &Room) -> bool {
+        if room.draft.is_some() {
+            return true;
+        }
+        match self.areas.get(&room.parent_id) {
+            Some(area) => area.read().await.draft.is_some(),
+            None => false
+        }
+    }
+
+    /// May `player` configure `area_id`?
+    ///
+    /// Admins may configure (and create) any area, other builders only
+    /// the areas they own.
+    pub async fn may_configure(&self, player: &Player, area_id: &str) -> bool {
+        if player.access.is_admin() {
+            return true;
+        }
+        if !player.access.is_builder() {
+            return false;
+        }
+        match self.areas.get(area_id) {
+            Some(area) => area.read().await.is_owner(player.id()),
+            None => false
+        }
+    }
+
     /// Find player by name.
     pub fn find_player(&self, name: &str) -> Option<Arc<RwLock<Player>>> {
         self.players.get(name).cloned()
@@ -315,11 +434,32 @@ impl World {
 #[async_trait]
 impl Tickable for World {
     async fn tick(&mut self, uptime: u64) {
+        let hour_changed = uptime / TICKS_PER_HOUR != self.uptime / TICKS_PER_HOUR;
         self.uptime = uptime;
+        if hour_changed {
+            // keep the calendar on disk, crash or not.
+            if let Err(e) = self.save_world_file() {
+                log::error!("Could not save the world file on the hour: {e:?}");
+            }
+            let now = self.time();
+            let sky = match now.hour {
+                SUNRISE_HOUR => Some("The sun rises in the east."),
+                SUNSET_HOUR => Some("The sun sets in the west."),
+                _ => None
+            };
+            for area in self.areas.values() {
+                let mut a = area.write().await;
+                a.shift_weather(now.season()).await;
+                if let Some(msg) = sky {
+                    a.tell_outdoors(msg).await;
+                }
+            }
+        }
         for area in self.areas.values() {
             area.write().await
                 .tick(self.uptime).await;
         }
+        self.mirror_reset_doors().await;
     }
 }
 
@@ -328,8 +468,7 @@ impl DoesSave for World {
     /// Save the [World]!
     async fn save(&mut self) -> Result<(), SaveError> {
         // base #.world file:
-        let file = std::fs::File::create(&self.filename)?;
-        serde_json::to_writer_pretty(file, &self)?;
+        self.save_world_file()?;
 
         // areas/#.area files:
         for area in self.areas.values() {
@@ -337,6 +476,12 @@ impl DoesSave for World {
             g.save().await?;
         }
 
+        // rooms/#.room files:
+        for room in self.rooms.values() {
+            let mut g = room.write().await;
+            g.save().await?;
+        }
+
         // // player save/#.save files - commented out but left here as a reminder of sorts.
         // for player in self.players.values() {
         //     let mut g = player.write().await;
@@ -363,7 +508,7 @@ macro_rules! world_for_tests {
                 let mut room_lock = r.write().await;
                 room_lock.title = "Alpha".into();
                 room_lock.description = "Alpha is the very, well, alpha of everything not omega.".into();
-                room_lock.exits.insert(Direction::East, Exit { destination: "clearing".into(), state: crate::world::exit::state::ExitState::Open{key_id:None} });
+                room_lock.exits.insert(Direction::East, Exit { destination: "clearing".into(), state: crate::world::exit::state::ExitState::Open{key_id:None}, hidden: None });
                 room_lock.parent_id = "area".into();
                 room_lock.parent = Arc::downgrade(&a);
             }
@@ -374,7 +519,7 @@ macro_rules! world_for_tests {
                 let mut room_lock = r.write().await;
                 room_lock.title = "Omega".to_string();
                 room_lock.description = "End of the road? Mayhap. But a nice clearing nonetheless!".into();
-                room_lock.exits.insert(Direction::West, Exit { destination: "void".into(), state: crate::world::exit::state::ExitState::Open{key_id:None} });
+                room_lock.exits.insert(Direction::West, Exit { destination: "void".into(), state: crate::world::exit::state::ExitState::Open{key_id:None}, hidden: None });
                 room_lock.parent_id = "area".into();
                 room_lock.parent = Arc::downgrade(&a);
             }
@@ -414,4 +559,26 @@ mod world_tests {
         }
         log::debug!("Lazing about.");
     }
+
+    #[tokio::test]
+    async fn reset_door_mirrors_other_side() {
+        use std::sync::Arc;
+        use tokio::sync::RwLock;
+        use crate::{util::direction::Direction, world::{World, area::{Area, reset::Reset}, exit::{Exit, state::ExitState}, room::Room}};
+
+        let w = world_for_tests!();
+        let shut = ExitState::Closed { key_id: None, jam: None };
+        {
+            let lock = w.read().await;
+            let mut area = lock.areas.get("root").unwrap().write().await;
+            for (id, room) in &lock.rooms {
+                area.rooms.insert(id.clone(), Arc::downgrade(room));
+            }
+            area.resets.push(Reset::Door { room: "void".into(), exit: Direction::East, state: shut.clone(), message: None });
+            assert_eq!(1, area.reset().await);
+        }
+        let lock = w.read().await;
+        lock.mirror_reset_doors().await;
+        assert_eq!(shut, lock.rooms["clearing"].read().await.exits[&Direction::West].state);
+    }
 }